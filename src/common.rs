@@ -1,9 +1,11 @@
+use std::borrow::Cow;
+use std::ops::Range;
 use std::str::Utf8Error;
 use std::sync::Arc;
 
 use datafusion::arrow::array::{
-    downcast_array, AnyDictionaryArray, Array, ArrayAccessor, ArrayRef, AsArray, DictionaryArray, LargeStringArray,
-    PrimitiveArray, PrimitiveBuilder, RunArray, StringArray, StringViewArray,
+    downcast_array, AnyDictionaryArray, Array, ArrayAccessor, ArrayRef, AsArray, DictionaryArray, GenericListArray,
+    LargeStringArray, OffsetSizeTrait, PrimitiveArray, PrimitiveBuilder, RunArray, StringArray, StringViewArray,
 };
 use datafusion::arrow::compute::kernels::cast;
 use datafusion::arrow::compute::take;
@@ -12,8 +14,9 @@ use datafusion::common::{exec_err, plan_err, Result as DataFusionResult, ScalarV
 use datafusion::logical_expr::ColumnarValue;
 use jiter::{Jiter, JiterError, Peek};
 
+use crate::common_path::{is_json_path, parse_json_path, pointer_token_index, CompareOp, FilterExpr, FilterLiteral};
 use crate::common_union::{
-    is_json_union, json_from_union_scalar, nested_json_array, nested_json_array_ref, TYPE_ID_NULL,
+    is_json_union, json_from_union_scalar, nested_json_array, nested_json_array_ref, nested_json_values, TYPE_ID_NULL,
 };
 
 /// General implementation of `ScalarUDFImpl::return_type`.
@@ -34,7 +37,7 @@ pub fn return_type_check(args: &[DataType], fn_name: &str, value_type: DataType)
         return plan_err!("Unexpected argument type to '{fn_name}' at position 1, expected a string, got {first:?}.");
     }
     args.iter().skip(1).enumerate().try_for_each(|(index, arg)| {
-        if is_str(arg) || is_int(arg) || dict_key_type(arg).is_some() {
+        if is_str(arg) || is_int(arg) || is_str_list(arg) || is_int_dict(arg) || dict_key_type(arg).is_some() {
             Ok(())
         } else {
             plan_err!(
@@ -55,8 +58,16 @@ fn is_str(d: &DataType) -> bool {
 }
 
 fn is_int(d: &DataType) -> bool {
-    // TODO we should support more types of int, but that's a longer task
-    matches!(d, DataType::UInt64 | DataType::Int64)
+    d.is_integer()
+}
+
+fn is_int_dict(d: &DataType) -> bool {
+    matches!(d, DataType::Dictionary(_, value) if is_int(value))
+}
+
+/// A list of strings, e.g. the reference tokens from `json_pointer`
+fn is_str_list(d: &DataType) -> bool {
+    matches!(d, DataType::List(field) | DataType::LargeList(field) if is_str(field.data_type()))
 }
 
 fn dict_key_type(d: &DataType) -> Option<DataType> {
@@ -68,22 +79,48 @@ fn dict_key_type(d: &DataType) -> Option<DataType> {
     None
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum JsonPath<'s> {
-    Key(&'s str),
+    Key(Cow<'s, str>),
     Index(usize),
+    /// Array element counting from the end, `IndexFromEnd(1)` is the last element
+    IndexFromEnd(usize),
+    /// Array elements from `start` (inclusive) to `end` (exclusive), negative bounds count from the end
+    Slice(Option<i64>, Option<i64>),
+    /// Every member of an object or element of an array, `*` in `JSONPath`
+    Wildcard,
+    /// The current value and all its descendants, `..` in `JSONPath`
+    RecursiveDescent,
+    /// Every member of an object or element of an array matching a filter, `[?(...)]` in `JSONPath`
+    Filter(Box<FilterExpr<'s>>),
+    /// The current value if it matches a filter, `? (...)` in Postgres `jsonpath`, in lax mode this applies
+    /// to each element of an array
+    Condition(Box<FilterExpr<'s>>),
+    /// A JSON Pointer reference token, an object member name or an array index depending on the value
+    Token(Cow<'s, str>),
     None,
 }
 
+impl JsonPath<'_> {
+    /// The array index this element refers to, if any.
+    pub(crate) fn array_index(&self) -> Option<usize> {
+        match self {
+            Self::Index(index) => Some(*index),
+            Self::Token(token) => pointer_token_index(token),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> From<&'a str> for JsonPath<'a> {
     fn from(key: &'a str) -> Self {
-        JsonPath::Key(key)
+        JsonPath::Key(Cow::Borrowed(key))
     }
 }
 
 impl From<u64> for JsonPath<'_> {
     fn from(index: u64) -> Self {
-        JsonPath::Index(usize::try_from(index).unwrap())
+        usize::try_from(index).map_or(Self::None, Self::Index)
     }
 }
 
@@ -91,56 +128,179 @@ impl From<i64> for JsonPath<'_> {
     fn from(index: i64) -> Self {
         match usize::try_from(index) {
             Ok(i) => Self::Index(i),
-            Err(_) => Self::None,
+            Err(_) => match usize::try_from(index.unsigned_abs()) {
+                Ok(i) => Self::IndexFromEnd(i),
+                Err(_) => Self::None,
+            },
         }
     }
 }
 
 #[derive(Debug)]
 enum JsonPathArgs<'a> {
-    Array(&'a ArrayRef),
+    /// At least one path argument is an array, so the path varies per row
+    Array(Vec<PathArg<'a>>),
     Scalars(Vec<JsonPath<'a>>),
 }
 
+/// A run of path arguments, either consecutive scalars compiled together or a single array.
+#[derive(Debug)]
+enum PathArg<'a> {
+    Scalar(Vec<JsonPath<'a>>),
+    Array(&'a ArrayRef),
+}
+
 impl<'s> JsonPathArgs<'s> {
     fn extract_path(path_args: &'s [ColumnarValue]) -> DataFusionResult<Self> {
-        // If there is a single argument as an array, we know how to handle it
-        if let Some((ColumnarValue::Array(array), &[])) = path_args.split_first() {
-            return Ok(Self::Array(array));
-        }
-
-        path_args
-            .iter()
-            .enumerate()
-            .map(|(pos, arg)| match arg {
-                ColumnarValue::Scalar(
-                    ScalarValue::Utf8(Some(s)) | ScalarValue::Utf8View(Some(s)) | ScalarValue::LargeUtf8(Some(s)),
-                ) => Ok(JsonPath::Key(s)),
-                ColumnarValue::Scalar(ScalarValue::UInt64(Some(i))) => Ok((*i).into()),
-                ColumnarValue::Scalar(ScalarValue::Int64(Some(i))) => Ok((*i).into()),
-                ColumnarValue::Scalar(
-                    ScalarValue::Null
-                    | ScalarValue::Utf8(None)
-                    | ScalarValue::Utf8View(None)
-                    | ScalarValue::LargeUtf8(None)
-                    | ScalarValue::UInt64(None)
-                    | ScalarValue::Int64(None),
-                ) => Ok(JsonPath::None),
-                ColumnarValue::Array(_) => {
-                    // if there was a single arg, which is an array, handled above in the
-                    // split_first case. So this is multiple args of which one is an array
-                    exec_err!("More than 1 path element is not supported when querying JSON using an array.")
+        let mut args = Vec::new();
+        let mut path = Vec::with_capacity(path_args.len());
+        for (pos, arg) in path_args.iter().enumerate() {
+            match arg {
+                ColumnarValue::Scalar(scalar) => push_scalar_path(&mut path, pos, scalar)?,
+                ColumnarValue::Array(array) => {
+                    if !path.is_empty() {
+                        args.push(PathArg::Scalar(std::mem::take(&mut path)));
+                    }
+                    args.push(PathArg::Array(array));
                 }
-                ColumnarValue::Scalar(arg) => exec_err!(
-                    "Unexpected argument type at position {}, expected string or int, got {arg:?}.",
-                    pos + 1
-                ),
-            })
-            .collect::<DataFusionResult<_>>()
-            .map(JsonPathArgs::Scalars)
+            }
+        }
+        if args.is_empty() {
+            Ok(JsonPathArgs::Scalars(path))
+        } else {
+            if !path.is_empty() {
+                args.push(PathArg::Scalar(path));
+            }
+            Ok(JsonPathArgs::Array(args))
+        }
     }
 }
 
+fn push_scalar_path<'s>(path: &mut Vec<JsonPath<'s>>, pos: usize, scalar: &'s ScalarValue) -> DataFusionResult<()> {
+    match scalar {
+        // a JSONPath string is compiled once here, and its elements spliced into the path
+        ScalarValue::Utf8(Some(s)) | ScalarValue::Utf8View(Some(s)) | ScalarValue::LargeUtf8(Some(s))
+            if is_json_path(s) =>
+        {
+            path.extend(parse_json_path(s)?);
+        }
+        ScalarValue::Utf8(Some(s)) | ScalarValue::Utf8View(Some(s)) | ScalarValue::LargeUtf8(Some(s)) => {
+            path.push(JsonPath::Key(Cow::Borrowed(s)));
+        }
+        ScalarValue::UInt8(Some(i)) => path.push(u64::from(*i).into()),
+        ScalarValue::UInt16(Some(i)) => path.push(u64::from(*i).into()),
+        ScalarValue::UInt32(Some(i)) => path.push(u64::from(*i).into()),
+        ScalarValue::UInt64(Some(i)) => path.push((*i).into()),
+        ScalarValue::Int8(Some(i)) => path.push(i64::from(*i).into()),
+        ScalarValue::Int16(Some(i)) => path.push(i64::from(*i).into()),
+        ScalarValue::Int32(Some(i)) => path.push(i64::from(*i).into()),
+        ScalarValue::Int64(Some(i)) => path.push((*i).into()),
+        ScalarValue::List(list) if !list.is_null(0) => path.extend(list_tokens(list.as_ref(), 0)?),
+        ScalarValue::LargeList(list) if !list.is_null(0) => path.extend(list_tokens(list.as_ref(), 0)?),
+        ScalarValue::Null
+        | ScalarValue::Utf8(None)
+        | ScalarValue::Utf8View(None)
+        | ScalarValue::LargeUtf8(None)
+        | ScalarValue::UInt8(None)
+        | ScalarValue::UInt16(None)
+        | ScalarValue::UInt32(None)
+        | ScalarValue::UInt64(None)
+        | ScalarValue::Int8(None)
+        | ScalarValue::Int16(None)
+        | ScalarValue::Int32(None)
+        | ScalarValue::Int64(None)
+        | ScalarValue::List(_)
+        | ScalarValue::LargeList(_) => path.push(JsonPath::None),
+        arg => {
+            return exec_err!(
+                "Unexpected argument type at position {}, expected string or int, got {arg:?}.",
+                pos + 1
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The path from literal path arguments, for functions which can't vary the path per row.
+pub(crate) fn scalar_path(path_args: &[ColumnarValue]) -> DataFusionResult<Vec<JsonPath<'_>>> {
+    match JsonPathArgs::extract_path(path_args)? {
+        JsonPathArgs::Scalars(path) => Ok(path),
+        JsonPathArgs::Array(_) => exec_err!("Expected literal path arguments, got an array."),
+    }
+}
+
+/// Path arguments which may be any mix of literals and columns, for functions which combine the path of each row
+/// with other arguments rather than using [`invoke`].
+pub(crate) struct RowPaths<'a>(Vec<PathColumn<'a>>);
+
+impl<'a> RowPaths<'a> {
+    pub(crate) fn try_new(path_args: &'a [ColumnarValue]) -> DataFusionResult<Self> {
+        let columns = match JsonPathArgs::extract_path(path_args)? {
+            JsonPathArgs::Scalars(path) => vec![PathColumn::Scalar(path)],
+            JsonPathArgs::Array(args) => args.iter().map(PathColumn::try_new).collect::<DataFusionResult<_>>()?,
+        };
+        Ok(Self(columns))
+    }
+
+    /// Replace the contents of `path` with the path for `row`, returns `false` if the row's path is null.
+    pub(crate) fn row_path(&self, row: usize, path: &mut Vec<JsonPath<'a>>) -> DataFusionResult<bool> {
+        path.clear();
+        for column in &self.0 {
+            if !column.extend_path(row, path)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// The JSON in each row of a string array, or the nested JSON in each row of a `JsonUnion` array.
+pub(crate) fn json_values(json_array: &ArrayRef) -> DataFusionResult<Vec<Option<&str>>> {
+    match json_array.data_type() {
+        DataType::Utf8 => Ok(json_array.as_string::<i32>().iter().collect()),
+        DataType::LargeUtf8 => Ok(json_array.as_string::<i64>().iter().collect()),
+        DataType::Utf8View => Ok(json_array.as_string_view().iter().collect()),
+        DataType::Null => Ok(vec![None; json_array.len()]),
+        other => {
+            let Some(values) = nested_json_values(json_array) else {
+                return exec_err!("unexpected json array type {:?}", other);
+            };
+            Ok(values)
+        }
+    }
+}
+
+/// Apply `f` to the values of a dictionary array, keeping its keys, or to any other array directly.
+pub(crate) fn map_dictionary_values(
+    array: &ArrayRef,
+    f: impl Fn(&ArrayRef) -> DataFusionResult<ArrayRef>,
+) -> DataFusionResult<ArrayRef> {
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        let values = f(dict.values())?;
+        Ok(Arc::new(cast_to_large_dictionary(dict)?.with_values(values)))
+    } else {
+        f(array)
+    }
+}
+
+/// Cast a dictionary array to its value type, or return any other array unchanged.
+pub(crate) fn unpack_dictionary(array: &ArrayRef) -> DataFusionResult<ArrayRef> {
+    match array.data_type() {
+        DataType::Dictionary(_, value_type) => Ok(cast(array, value_type)?),
+        _ => Ok(Arc::clone(array)),
+    }
+}
+
+/// The JSON argument and the path arguments, without the `options` arguments between them which the `_with`
+/// variants of functions take.
+pub(crate) fn without_options<T: Clone>(args: &[T], options: usize) -> Vec<T> {
+    args.iter()
+        .take(1)
+        .chain(args.iter().skip(1 + options))
+        .cloned()
+        .collect()
+}
+
 pub trait InvokeResult {
     type Item;
     type Builder;
@@ -156,9 +316,23 @@ pub trait InvokeResult {
     fn scalar(value: Option<Self::Item>) -> ScalarValue;
 }
 
+/// Apply `jiter_find` to each JSON value and path, for functions which return a single value per row.
+///
+/// Array slices in the path are replaced by a JSON array of the selected elements.
 pub fn invoke<R: InvokeResult>(
     args: &[ColumnarValue],
     jiter_find: impl Fn(Option<&str>, &[JsonPath]) -> Result<R::Item, GetError>,
+) -> DataFusionResult<ColumnarValue> {
+    invoke_query::<R>(args, |opt_json: Option<&str>, path: &[JsonPath]| {
+        find_with_slices(opt_json, path, &jiter_find)
+    })
+}
+
+/// Like [`invoke`], but the path is passed to `jiter_find` unchanged, for functions which return every
+/// value matched.
+pub fn invoke_query<R: InvokeResult>(
+    args: &[ColumnarValue],
+    jiter_find: impl Fn(Option<&str>, &[JsonPath]) -> Result<R::Item, GetError>,
 ) -> DataFusionResult<ColumnarValue> {
     let Some((json_arg, path_args)) = args.split_first() else {
         return exec_err!("expected at least one argument");
@@ -166,14 +340,14 @@ pub fn invoke<R: InvokeResult>(
 
     let path = JsonPathArgs::extract_path(path_args)?;
     match (json_arg, path) {
-        (ColumnarValue::Array(json_array), JsonPathArgs::Array(path_array)) => {
-            invoke_array_array::<R>(json_array, path_array, jiter_find).map(ColumnarValue::Array)
+        (ColumnarValue::Array(json_array), JsonPathArgs::Array(path_args)) => {
+            invoke_array_array::<R>(json_array, &path_args, jiter_find).map(ColumnarValue::Array)
         }
         (ColumnarValue::Array(json_array), JsonPathArgs::Scalars(path)) => {
             invoke_array_scalars::<R>(json_array, &path, jiter_find).map(ColumnarValue::Array)
         }
-        (ColumnarValue::Scalar(s), JsonPathArgs::Array(path_array)) => {
-            invoke_scalar_array::<R>(s, path_array, jiter_find)
+        (ColumnarValue::Scalar(s), JsonPathArgs::Array(path_args)) => {
+            invoke_scalar_array::<R>(s, &path_args, jiter_find)
         }
         (ColumnarValue::Scalar(s), JsonPathArgs::Scalars(path)) => {
             invoke_scalar_scalars(s, &path, jiter_find, R::scalar)
@@ -191,7 +365,7 @@ fn null_result<R: InvokeResult>(len: usize) -> DataFusionResult<ArrayRef> {
 
 fn invoke_array_array<R: InvokeResult>(
     json_array: &ArrayRef,
-    path_array: &ArrayRef,
+    path_args: &[PathArg],
     jiter_find: impl Fn(Option<&str>, &[JsonPath]) -> Result<R::Item, GetError>,
 ) -> DataFusionResult<ArrayRef> {
     match json_array.data_type() {
@@ -200,7 +374,7 @@ fn invoke_array_array<R: InvokeResult>(
             let json_array = cast_to_large_dictionary(json_array.as_any_dictionary())?;
             let output = zip_apply::<R>(
                 json_array.downcast_dict::<StringArray>().unwrap(),
-                path_array,
+                path_args,
                 jiter_find,
             )?;
             if R::ACCEPT_DICT_RETURN {
@@ -214,7 +388,7 @@ fn invoke_array_array<R: InvokeResult>(
             let json_array = cast_to_large_dictionary(json_array.as_any_dictionary())?;
             let output = zip_apply::<R>(
                 json_array.downcast_dict::<LargeStringArray>().unwrap(),
-                path_array,
+                path_args,
                 jiter_find,
             )?;
             if R::ACCEPT_DICT_RETURN {
@@ -230,24 +404,24 @@ fn invoke_array_array<R: InvokeResult>(
             // dictionary and then recurse.
             if let Some(child_array) = nested_json_array_ref(
                 json_array.as_any_dictionary().values(),
-                is_object_lookup_array(path_array.data_type()),
+                is_object_lookup_args(path_args),
             ) {
                 invoke_array_array::<R>(
                     &(Arc::new(json_array.as_any_dictionary().with_values(child_array.clone())) as _),
-                    path_array,
+                    path_args,
                     jiter_find,
                 )
             } else {
                 exec_err!("unexpected json array type {:?}", other_dict_type)
             }
         }
-        DataType::Utf8 => zip_apply::<R>(json_array.as_string::<i32>(), path_array, jiter_find),
-        DataType::LargeUtf8 => zip_apply::<R>(json_array.as_string::<i64>(), path_array, jiter_find),
-        DataType::Utf8View => zip_apply::<R>(json_array.as_string_view(), path_array, jiter_find),
+        DataType::Utf8 => zip_apply::<R>(json_array.as_string::<i32>(), path_args, jiter_find),
+        DataType::LargeUtf8 => zip_apply::<R>(json_array.as_string::<i64>(), path_args, jiter_find),
+        DataType::Utf8View => zip_apply::<R>(json_array.as_string_view(), path_args, jiter_find),
         DataType::Null => null_result::<R>(json_array.len()),
         other => {
-            if let Some(string_array) = nested_json_array(json_array, is_object_lookup_array(path_array.data_type())) {
-                zip_apply::<R>(string_array, path_array, jiter_find)
+            if let Some(string_array) = nested_json_array(json_array, is_object_lookup_args(path_args)) {
+                zip_apply::<R>(string_array, path_args, jiter_find)
             } else {
                 exec_err!("unexpected json array type {:?}", other)
             }
@@ -341,23 +515,30 @@ fn invoke_array_scalars<R: InvokeResult>(
 
 fn invoke_scalar_array<R: InvokeResult>(
     scalar: &ScalarValue,
-    path_array: &ArrayRef,
+    path_args: &[PathArg],
     jiter_find: impl Fn(Option<&str>, &[JsonPath]) -> Result<R::Item, GetError>,
 ) -> DataFusionResult<ColumnarValue> {
     let s = extract_json_scalar(scalar)?;
     let arr = s.map_or_else(|| StringArray::new_null(1), |s| StringArray::new_scalar(s).into_inner());
 
+    let len = path_args
+        .iter()
+        .find_map(|arg| match arg {
+            PathArg::Array(array) => Some(array.len()),
+            PathArg::Scalar(_) => None,
+        })
+        .unwrap_or(1);
+
     // TODO: possible optimization here if path_array is a dictionary; can apply against the
     // dictionary values directly for less work
     zip_apply::<R>(
         RunArray::try_new(
-            &PrimitiveArray::<Int64Type>::new_scalar(i64::try_from(path_array.len()).expect("len out of i64 range"))
-                .into_inner(),
+            &PrimitiveArray::<Int64Type>::new_scalar(i64::try_from(len).expect("len out of i64 range")).into_inner(),
             &arr,
         )?
         .downcast::<StringArray>()
         .expect("type known"),
-        path_array,
+        path_args,
         jiter_find,
     )
     // FIXME edge cases where scalar is wrapped in a dictionary, should return a dictionary?
@@ -378,7 +559,7 @@ fn invoke_scalar_scalars<I>(
 
 fn zip_apply<'a, R: InvokeResult>(
     json_array: impl ArrayAccessor<Item = &'a str>,
-    path_array: &ArrayRef,
+    path_args: &[PathArg],
     jiter_find: impl Fn(Option<&'a str>, &[JsonPath]) -> Result<R::Item, GetError>,
 ) -> DataFusionResult<ArrayRef> {
     fn get_array_values<'j, 'p, P: Into<JsonPath<'p>>>(
@@ -415,6 +596,11 @@ fn zip_apply<'a, R: InvokeResult>(
         R::finish(builder)
     }
 
+    // a single array path argument can be consumed directly
+    let [PathArg::Array(path_array)] = path_args else {
+        return zip_apply_mixed::<R>(json_array, path_args, jiter_find);
+    };
+
     match path_array.data_type() {
         // for string dictionaries, cast dictionary keys to larger types to avoid generic explosion
         DataType::Dictionary(_, value_type) if value_type.as_ref() == &DataType::Utf8 => {
@@ -441,14 +627,14 @@ fn zip_apply<'a, R: InvokeResult>(
                 jiter_find,
             )
         }
-        // for integer dictionaries, cast them directly to the inner type because it basically costs
+        // for integer dictionaries, cast them directly to the widest integer type because it basically costs
         // the same as building a new key array anyway
-        DataType::Dictionary(_, value_type) if value_type.as_ref() == &DataType::Int64 => inner::<_, R>(
+        DataType::Dictionary(_, value_type) if value_type.is_signed_integer() => inner::<_, R>(
             json_array,
             cast(path_array, &DataType::Int64)?.as_primitive::<Int64Type>(),
             jiter_find,
         ),
-        DataType::Dictionary(_, value_type) if value_type.as_ref() == &DataType::UInt64 => inner::<_, R>(
+        DataType::Dictionary(_, value_type) if value_type.is_unsigned_integer() => inner::<_, R>(
             json_array,
             cast(path_array, &DataType::UInt64)?.as_primitive::<UInt64Type>(),
             jiter_find,
@@ -459,6 +645,19 @@ fn zip_apply<'a, R: InvokeResult>(
         DataType::Utf8View => inner::<_, R>(json_array, path_array.as_string_view(), jiter_find),
         DataType::Int64 => inner::<_, R>(json_array, path_array.as_primitive::<Int64Type>(), jiter_find),
         DataType::UInt64 => inner::<_, R>(json_array, path_array.as_primitive::<UInt64Type>(), jiter_find),
+        // narrower integers are widened, so they're handled the same way as 64 bit integers
+        DataType::Int8 | DataType::Int16 | DataType::Int32 => inner::<_, R>(
+            json_array,
+            cast(path_array, &DataType::Int64)?.as_primitive::<Int64Type>(),
+            jiter_find,
+        ),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 => inner::<_, R>(
+            json_array,
+            cast(path_array, &DataType::UInt64)?.as_primitive::<UInt64Type>(),
+            jiter_find,
+        ),
+        // each row of a list is a whole path, e.g. from `json_pointer`
+        DataType::List(_) | DataType::LargeList(_) => zip_apply_mixed::<R>(json_array, path_args, jiter_find),
         other => {
             exec_err!(
                 "unexpected second argument type, expected string or int array, got {:?}",
@@ -468,6 +667,148 @@ fn zip_apply<'a, R: InvokeResult>(
     }
 }
 
+/// Apply `jiter_find` where the path is made up of any mix of scalar and array arguments, building the path
+/// for each row.
+#[allow(clippy::needless_pass_by_value)] // ArrayAccessor is implemented on references
+fn zip_apply_mixed<'a, R: InvokeResult>(
+    json_array: impl ArrayAccessor<Item = &'a str>,
+    path_args: &[PathArg],
+    jiter_find: impl Fn(Option<&'a str>, &[JsonPath]) -> Result<R::Item, GetError>,
+) -> DataFusionResult<ArrayRef> {
+    let columns = path_args
+        .iter()
+        .map(PathColumn::try_new)
+        .collect::<DataFusionResult<Vec<_>>>()?;
+    let mut builder = R::builder(json_array.len());
+    let mut path = Vec::new();
+    'rows: for i in 0..json_array.len() {
+        path.clear();
+        for column in &columns {
+            if !column.extend_path(i, &mut path)? {
+                R::append_value(&mut builder, None);
+                continue 'rows;
+            }
+        }
+        let opt_json = if json_array.is_null(i) {
+            None
+        } else {
+            Some(json_array.value(i))
+        };
+        R::append_value(&mut builder, jiter_find(opt_json, &path).ok());
+    }
+    R::finish(builder)
+}
+
+/// A path argument for [`zip_apply_mixed`], prepared so the elements for each row can be looked up cheaply.
+enum PathColumn<'a> {
+    /// The same elements for every row
+    Scalar(Vec<JsonPath<'a>>),
+    /// One element per row, `None` where the row is null
+    Elements(Vec<Option<JsonPath<'a>>>),
+    /// A whole path per row, e.g. from `json_pointer`
+    Lists(&'a ArrayRef),
+}
+
+impl<'a> PathColumn<'a> {
+    fn try_new(arg: &PathArg<'a>) -> DataFusionResult<Self> {
+        match arg {
+            PathArg::Scalar(path) => Ok(Self::Scalar(path.clone())),
+            PathArg::Array(array) => {
+                let array: &'a ArrayRef = array;
+                if let DataType::List(_) | DataType::LargeList(_) = array.data_type() {
+                    Ok(Self::Lists(array))
+                } else {
+                    path_elements(array).map(Self::Elements)
+                }
+            }
+        }
+    }
+
+    /// Add the elements for `row` to `path`, returns `false` if the row is null.
+    fn extend_path(&self, row: usize, path: &mut Vec<JsonPath<'a>>) -> DataFusionResult<bool> {
+        match self {
+            Self::Scalar(elements) => path.extend(elements.iter().cloned()),
+            Self::Elements(elements) => match &elements[row] {
+                Some(element) => path.push(element.clone()),
+                None => return Ok(false),
+            },
+            Self::Lists(lists) => {
+                if lists.is_null(row) {
+                    return Ok(false);
+                }
+                match lists.data_type() {
+                    DataType::LargeList(_) => path.extend(list_tokens(lists.as_list::<i64>(), row)?),
+                    _ => path.extend(list_tokens(lists.as_list::<i32>(), row)?),
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// The path element from each row of an array, dictionaries are converted once per dictionary value.
+fn path_elements(array: &ArrayRef) -> DataFusionResult<Vec<Option<JsonPath<'_>>>> {
+    fn collect<'p, P: Into<JsonPath<'p>>>(values: &impl ArrayAccessor<Item = P>) -> Vec<Option<JsonPath<'p>>> {
+        (0..values.len())
+            .map(|i| (!values.is_null(i)).then(|| values.value(i).into()))
+            .collect()
+    }
+
+    match array.data_type() {
+        DataType::Dictionary(_, _) => {
+            let dict = array.as_any_dictionary();
+            let values = path_elements(dict.values())?;
+            Ok(dict
+                .normalized_keys()
+                .into_iter()
+                .enumerate()
+                .map(|(i, key)| {
+                    if array.is_null(i) {
+                        None
+                    } else {
+                        values.get(key).cloned().flatten()
+                    }
+                })
+                .collect())
+        }
+        DataType::Utf8 => Ok(collect(&array.as_string::<i32>())),
+        DataType::LargeUtf8 => Ok(collect(&array.as_string::<i64>())),
+        DataType::Utf8View => Ok(collect(&array.as_string_view())),
+        DataType::Int64 => Ok(collect(&array.as_primitive::<Int64Type>())),
+        DataType::UInt64 => Ok(collect(&array.as_primitive::<UInt64Type>())),
+        other if other.is_signed_integer() => Ok(collect(&cast(array, &DataType::Int64)?.as_primitive::<Int64Type>())),
+        other if other.is_unsigned_integer() => {
+            Ok(collect(&cast(array, &DataType::UInt64)?.as_primitive::<UInt64Type>()))
+        }
+        other => exec_err!("unexpected path argument type, expected string or int array, got {other:?}"),
+    }
+}
+
+/// The strings in row `index` of a list as [`JsonPath::Token`]s, null strings never match.
+fn list_tokens<O: OffsetSizeTrait>(list: &GenericListArray<O>, index: usize) -> DataFusionResult<Vec<JsonPath<'_>>> {
+    fn tokens<'a>(items: &impl ArrayAccessor<Item = &'a str>, range: Range<usize>) -> Vec<JsonPath<'a>> {
+        range
+            .map(|i| {
+                if items.is_null(i) {
+                    JsonPath::None
+                } else {
+                    JsonPath::Token(Cow::Borrowed(items.value(i)))
+                }
+            })
+            .collect()
+    }
+
+    let offsets = list.value_offsets();
+    let range = offsets[index].as_usize()..offsets[index + 1].as_usize();
+    let items = list.values();
+    match items.data_type() {
+        DataType::Utf8 => Ok(tokens(&items.as_string::<i32>(), range)),
+        DataType::LargeUtf8 => Ok(tokens(&items.as_string::<i64>(), range)),
+        DataType::Utf8View => Ok(tokens(&items.as_string_view(), range)),
+        other => exec_err!("unexpected path list item type, expected string, got {other:?}"),
+    }
+}
+
 fn extract_json_scalar(scalar: &ScalarValue) -> DataFusionResult<Option<&str>> {
     match scalar {
         ScalarValue::Dictionary(_, b) => extract_json_scalar(b.as_ref()),
@@ -489,6 +830,14 @@ fn is_object_lookup(path: &[JsonPath]) -> bool {
     }
 }
 
+fn is_object_lookup_args(path_args: &[PathArg]) -> bool {
+    match path_args.first() {
+        Some(PathArg::Scalar(path)) => is_object_lookup(path),
+        Some(PathArg::Array(array)) => is_object_lookup_array(array.data_type()),
+        None => false,
+    }
+}
+
 fn is_object_lookup_array(data_type: &DataType) -> bool {
     match data_type {
         DataType::Dictionary(_, value_type) => is_object_lookup_array(value_type),
@@ -526,28 +875,39 @@ pub fn jiter_json_find<'j>(opt_json: Option<&'j str>, path: &[JsonPath]) -> Opti
     let json_str = opt_json?;
     let mut jiter = Jiter::new(json_str.as_bytes());
     let mut peek = jiter.peek().ok()?;
-    for element in path {
+    for (position, element) in path.iter().enumerate() {
         match element {
-            JsonPath::Key(key) if peek == Peek::Object => {
+            JsonPath::Key(key) | JsonPath::Token(key) if peek == Peek::Object => {
                 let mut next_key = jiter.known_object().ok()??;
 
-                while next_key != *key {
+                while next_key != &**key {
                     jiter.next_skip().ok()?;
                     next_key = jiter.next_key().ok()??;
                 }
 
                 peek = jiter.peek().ok()?;
             }
-            JsonPath::Index(index) if peek == Peek::Array => {
+            JsonPath::Index(_) | JsonPath::Token(_) if peek == Peek::Array => {
+                let index = element.array_index()?;
                 let mut array_item = jiter.known_array().ok()??;
 
-                for _ in 0..*index {
+                for _ in 0..index {
                     jiter.known_skip(array_item).ok()?;
                     array_item = jiter.array_step().ok()??;
                 }
 
                 peek = array_item;
             }
+            JsonPath::Wildcard
+            | JsonPath::RecursiveDescent
+            | JsonPath::IndexFromEnd(_)
+            | JsonPath::Slice(..)
+            | JsonPath::Filter(_)
+            | JsonPath::Condition(_) => {
+                // this element can match more than one branch, or needs the length of the array, walk
+                // from the current value and take the first match
+                return first_match(json_str.as_bytes(), jiter.current_index(), &path[position..]);
+            }
             _ => {
                 return None;
             }
@@ -556,6 +916,231 @@ pub fn jiter_json_find<'j>(opt_json: Option<&'j str>, path: &[JsonPath]) -> Opti
     Some((jiter, peek))
 }
 
+/// Find every value matched by `path`.
+///
+/// Matches are returned in the order they're found, with recursive descent matching a value before its
+/// descendants.
+///
+/// Unlike [`jiter_json_find`] which follows a single branch, this collects all the matches of multi-match
+/// elements like [`JsonPath::Wildcard`] and [`JsonPath::RecursiveDescent`]. Returns `None` if there's no
+/// JSON to search or it isn't valid.
+pub fn jiter_json_find_all<'j>(opt_json: Option<&'j str>, path: &[JsonPath]) -> Option<Vec<(Jiter<'j>, Peek)>> {
+    let json_str = opt_json?;
+    // the walk skips the parts of the document which aren't matched, so check it's all valid first
+    let mut jiter = Jiter::new(json_str.as_bytes());
+    jiter.next_skip().ok()?;
+    jiter.finish().ok()?;
+    let mut matches = Vec::new();
+    walk_path(json_str.as_bytes(), 0, path, &mut |jiter: Jiter<'j>, peek: Peek| {
+        matches.push((jiter, peek));
+        true
+    });
+    Some(matches)
+}
+
+/// Find the first value matched by `path` from the value starting at byte `offset` of `json`.
+fn first_match<'j>(json: &'j [u8], offset: usize, path: &[JsonPath]) -> Option<(Jiter<'j>, Peek)> {
+    let mut first = None;
+    walk_path(json, offset, path, &mut |jiter: Jiter<'j>, peek: Peek| {
+        first = Some((jiter, peek));
+        false
+    });
+    first
+}
+
+/// Walk `path` from the value starting at byte `offset` of `json`, calling `on_match` with a [`Jiter`]
+/// positioned at each value matched.
+///
+/// Returns `false` as soon as `on_match` does, to stop the walk. Invalid JSON ends the walk of the
+/// branch it is found in.
+fn walk_path<'j>(
+    json: &'j [u8],
+    offset: usize,
+    path: &[JsonPath],
+    on_match: &mut dyn FnMut(Jiter<'j>, Peek) -> bool,
+) -> bool {
+    try_walk_path(json, offset, path, on_match).unwrap_or(true)
+}
+
+fn try_walk_path<'j>(
+    json: &'j [u8],
+    offset: usize,
+    path: &[JsonPath],
+    on_match: &mut dyn FnMut(Jiter<'j>, Peek) -> bool,
+) -> Result<bool, JiterError> {
+    // branches are walked with a fresh jiter over the rest of the input, so indexes from `jiter` are
+    // relative to `offset`
+    let mut jiter = Jiter::new(&json[offset..]);
+    let peek = jiter.peek()?;
+    let Some((element, rest)) = path.split_first() else {
+        return Ok(on_match(jiter, peek));
+    };
+    match element {
+        JsonPath::Key(key) | JsonPath::Token(key) if peek == Peek::Object => {
+            let mut opt_key = jiter.known_object()?;
+            while let Some(next_key) = opt_key {
+                if next_key == &**key {
+                    return Ok(walk_path(json, offset + jiter.current_index(), rest, on_match));
+                }
+                jiter.next_skip()?;
+                opt_key = jiter.next_key()?;
+            }
+        }
+        JsonPath::Index(_) | JsonPath::Token(_) if peek == Peek::Array => {
+            let Some(index) = element.array_index() else {
+                return Ok(true);
+            };
+            let mut opt_item = jiter.known_array()?;
+            let mut item_index = 0;
+            while let Some(item_peek) = opt_item {
+                if item_index == index {
+                    return Ok(walk_path(json, offset + jiter.current_index(), rest, on_match));
+                }
+                jiter.known_skip(item_peek)?;
+                opt_item = jiter.array_step()?;
+                item_index += 1;
+            }
+        }
+        JsonPath::IndexFromEnd(from_end) if peek == Peek::Array => {
+            let children = child_offsets(&mut jiter, peek)?;
+            if let Some(child) = children.len().checked_sub(*from_end).and_then(|i| children.get(i)) {
+                return Ok(walk_path(json, offset + child, rest, on_match));
+            }
+        }
+        JsonPath::Slice(start, end) if peek == Peek::Array => {
+            let children = child_offsets(&mut jiter, peek)?;
+            for child in &children[slice_range(*start, *end, children.len())] {
+                if !walk_path(json, offset + child, rest, on_match) {
+                    return Ok(false);
+                }
+            }
+        }
+        JsonPath::Filter(filter) => {
+            return for_each_child(&mut jiter, peek, |child| {
+                !filter_matches(json, offset + child, filter) || walk_path(json, offset + child, rest, on_match)
+            });
+        }
+        JsonPath::Condition(filter) if peek == Peek::Array => {
+            return for_each_child(&mut jiter, peek, |child| {
+                !filter_matches(json, offset + child, filter) || walk_path(json, offset + child, rest, on_match)
+            });
+        }
+        JsonPath::Condition(filter) if filter_matches(json, offset, filter) => {
+            return Ok(walk_path(json, offset, rest, on_match));
+        }
+        JsonPath::Wildcard => {
+            return for_each_child(&mut jiter, peek, |child| {
+                walk_path(json, offset + child, rest, on_match)
+            });
+        }
+        JsonPath::RecursiveDescent => {
+            // apply the rest of the path to this value, then the whole path (still starting with `..`)
+            // to each of its children
+            if !walk_path(json, offset, rest, on_match) {
+                return Ok(false);
+            }
+            return for_each_child(&mut jiter, peek, |child| {
+                walk_path(json, offset + child, path, on_match)
+            });
+        }
+        _ => {}
+    }
+    Ok(true)
+}
+
+/// Call `f` with the index of each member value of an object, or each element of an array, stopping
+/// if `f` returns `false`.
+fn for_each_child(jiter: &mut Jiter, peek: Peek, mut f: impl FnMut(usize) -> bool) -> Result<bool, JiterError> {
+    if peek == Peek::Object {
+        let mut opt_key = jiter.known_object()?;
+        while opt_key.is_some() {
+            let value_peek = jiter.peek()?;
+            if !f(jiter.current_index()) {
+                return Ok(false);
+            }
+            jiter.known_skip(value_peek)?;
+            opt_key = jiter.next_key()?;
+        }
+    } else if peek == Peek::Array {
+        let mut opt_item = jiter.known_array()?;
+        while let Some(item_peek) = opt_item {
+            if !f(jiter.current_index()) {
+                return Ok(false);
+            }
+            jiter.known_skip(item_peek)?;
+            opt_item = jiter.array_step()?;
+        }
+    }
+    Ok(true)
+}
+
+/// Whether the JSON document satisfies `predicate`, `None` if there's no JSON.
+pub(crate) fn json_predicate_matches(opt_json: Option<&str>, predicate: &FilterExpr) -> Option<bool> {
+    let json_str = opt_json?;
+    Some(filter_matches(json_str.as_bytes(), 0, predicate))
+}
+
+/// Whether the whole of `json` is a valid JSON value, unlike finding a value which stops reading once it's found.
+pub(crate) fn is_valid_json(json: &str) -> bool {
+    let mut jiter = Jiter::new(json.as_bytes());
+    jiter.next_skip().and_then(|()| jiter.finish()).is_ok()
+}
+
+/// Whether the value starting at byte `offset` of `json` satisfies `filter`.
+fn filter_matches(json: &[u8], offset: usize, filter: &FilterExpr) -> bool {
+    match filter {
+        FilterExpr::Exists(path) => first_match(json, offset, path).is_some(),
+        FilterExpr::Compare(path, op, literal) => {
+            // like `Exists`, true if any of the values matched by the path compares as expected
+            let mut found = false;
+            walk_path(json, offset, path, &mut |mut jiter: Jiter, peek: Peek| {
+                let value = match peek {
+                    Peek::Null => Ok(FilterLiteral::Null),
+                    Peek::True | Peek::False => jiter.known_bool(peek).map(FilterLiteral::Bool),
+                    Peek::String => jiter.known_str().map(|s| FilterLiteral::Str(Cow::Borrowed(s))),
+                    // arrays and objects aren't equal to any literal
+                    Peek::Array | Peek::Object => {
+                        found = *op == CompareOp::Ne;
+                        return !found;
+                    }
+                    _ => jiter.known_float(peek).map(FilterLiteral::Number),
+                };
+                found = value.is_ok_and(|value| op.apply(&value, literal));
+                !found
+            });
+            found
+        }
+        FilterExpr::And(left, right) => filter_matches(json, offset, left) && filter_matches(json, offset, right),
+        FilterExpr::Or(left, right) => filter_matches(json, offset, left) || filter_matches(json, offset, right),
+        FilterExpr::Not(inner) => !filter_matches(json, offset, inner),
+    }
+}
+
+fn child_offsets(jiter: &mut Jiter, peek: Peek) -> Result<Vec<usize>, JiterError> {
+    let mut children = Vec::new();
+    for_each_child(jiter, peek, |child| {
+        children.push(child);
+        true
+    })?;
+    Ok(children)
+}
+
+/// Resolve the bounds of a [`JsonPath::Slice`] against an array of length `len`, out of range bounds are
+/// clamped to the array.
+fn slice_range(start: Option<i64>, end: Option<i64>, len: usize) -> std::ops::Range<usize> {
+    let resolve = |bound: i64| {
+        let distance = usize::try_from(bound.unsigned_abs()).unwrap_or(usize::MAX);
+        if bound < 0 {
+            len.saturating_sub(distance)
+        } else {
+            distance.min(len)
+        }
+    };
+    let start = start.map_or(0, resolve);
+    let end = end.map_or(len, resolve);
+    start..end.max(start)
+}
+
 macro_rules! get_err {
     () => {
         Err(GetError)
@@ -577,6 +1162,37 @@ impl From<Utf8Error> for GetError {
     }
 }
 
+/// Apply `jiter_find` to `path`, first replacing the array at each [`JsonPath::Slice`] with a new JSON array
+/// of the selected elements, so the remainder of the path applies to that array.
+pub(crate) fn find_with_slices<T>(
+    opt_json: Option<&str>,
+    path: &[JsonPath],
+    jiter_find: &impl Fn(Option<&str>, &[JsonPath]) -> Result<T, GetError>,
+) -> Result<T, GetError> {
+    let Some(slice_position) = path.iter().position(|element| matches!(element, JsonPath::Slice(..))) else {
+        return jiter_find(opt_json, path);
+    };
+    let JsonPath::Slice(start, end) = path[slice_position] else {
+        unreachable!()
+    };
+    let Some((mut jiter, peek)) = jiter_json_find(opt_json, &path[..slice_position]) else {
+        return get_err!();
+    };
+    if peek != Peek::Array {
+        return get_err!();
+    }
+    let mut items = Vec::new();
+    let mut opt_item = jiter.known_array()?;
+    while let Some(item_peek) = opt_item {
+        let item_start = jiter.current_index();
+        jiter.known_skip(item_peek)?;
+        items.push(std::str::from_utf8(jiter.slice_to_current(item_start))?);
+        opt_item = jiter.array_step()?;
+    }
+    let sliced = format!("[{}]", items[slice_range(start, end, items.len())].join(","));
+    find_with_slices(Some(&sliced), &path[slice_position + 1..], jiter_find)
+}
+
 /// Set keys to null where the union member is null.
 ///
 /// This is a workaround to <https://github.com/apache/arrow-rs/issues/6017#issuecomment-2352756753>
@@ -0,0 +1,204 @@
+use std::ops::Range;
+
+use datafusion::common::{exec_err, Result as DataFusionResult};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs};
+use jiter::{Jiter, Peek};
+
+use crate::common::{json_values, unpack_dictionary, GetError, JsonPath, RowPaths};
+use crate::common_encode::encode_rows;
+
+/// Where the value at a path is in a JSON document, or where it would be added.
+#[derive(Debug)]
+pub(crate) enum Target {
+    /// The existing value, and its entry in the parent object or array, which is `None` for the whole document
+    Found {
+        value: Range<usize>,
+        /// The bytes to delete to remove the value, including its key and one separating comma
+        entry: Option<Range<usize>>,
+    },
+    /// The value is missing, it would be added at the offset `at` after `prefix`, e.g. `,"key":`
+    Missing { at: usize, prefix: Vec<u8> },
+    /// The parent of the value is missing, or isn't an object or array
+    Unreachable,
+}
+
+impl Target {
+    fn offset(self, by: usize) -> Self {
+        let shift = |range: Range<usize>| range.start + by..range.end + by;
+        match self {
+            Self::Found { value, entry } => Self::Found {
+                value: shift(value),
+                entry: entry.map(shift),
+            },
+            Self::Missing { at, prefix } => Self::Missing { at: at + by, prefix },
+            Self::Unreachable => Self::Unreachable,
+        }
+    }
+}
+
+/// Edit the JSON document in the first argument at the path made up of the following arguments up to `path_end`.
+///
+/// `edit` writes the edited document for a row, given the document and the target of the path, or returns `false`
+/// if the result is null. The result is also null if the document is null or invalid, or the path is null.
+pub(crate) fn edit_rows(
+    args: &ScalarFunctionArgs,
+    path_end: usize,
+    fn_name: &str,
+    mut edit: impl FnMut(usize, &str, Target, &mut Vec<u8>) -> DataFusionResult<bool>,
+) -> DataFusionResult<ColumnarValue> {
+    let Some((json_arg, path_args)) = args.args[..path_end].split_first() else {
+        return exec_err!("The '{fn_name}' function requires a JSON value and a path.");
+    };
+    let (json_array, json_scalar) = match json_arg {
+        ColumnarValue::Array(array) => (unpack_dictionary(array)?, false),
+        ColumnarValue::Scalar(scalar) => (unpack_dictionary(&scalar.to_array()?)?, true),
+    };
+    let jsons = json_values(&json_array)?;
+    let paths = RowPaths::try_new(path_args)?;
+    let mut path = Vec::new();
+    encode_rows(&args.args, args.number_rows, |row, out| {
+        let Some(json) = jsons[if json_scalar { 0 } else { row }] else {
+            return Ok(false);
+        };
+        if !paths.row_path(row, &mut path)? {
+            return Ok(false);
+        }
+        if path.iter().any(|element| !is_editable(element)) {
+            return exec_err!("The '{fn_name}' function only supports paths of object keys and array indices.");
+        }
+        let Ok(target) = find_target(json.as_bytes(), &path) else {
+            return Ok(false);
+        };
+        edit(row, json, target, out)
+    })
+}
+
+/// Only object keys and array indices identify a single value to edit.
+fn is_editable(element: &JsonPath) -> bool {
+    matches!(
+        element,
+        JsonPath::Key(_) | JsonPath::Index(_) | JsonPath::IndexFromEnd(_) | JsonPath::Token(_) | JsonPath::None
+    )
+}
+
+/// Find the value at `path`, the whole document is checked to be valid JSON first so the result of splicing bytes
+/// into it is too.
+fn find_target(json: &[u8], path: &[JsonPath]) -> Result<Target, GetError> {
+    let mut jiter = Jiter::new(json);
+    let peek = jiter.peek()?;
+    let start = jiter.current_index();
+    jiter.known_skip(peek)?;
+    jiter.finish()?;
+    let mut target = Target::Found {
+        value: start..jiter.current_index(),
+        entry: None,
+    };
+
+    for element in path {
+        let Target::Found { value, .. } = target else {
+            return Ok(Target::Unreachable);
+        };
+        let parent = &json[value.clone()];
+        let mut jiter = Jiter::new(parent);
+        target = match (jiter.peek()?, element) {
+            (Peek::Object, JsonPath::Key(key) | JsonPath::Token(key)) => find_member(parent, &mut jiter, key)?,
+            (Peek::Array, JsonPath::Index(_) | JsonPath::IndexFromEnd(_) | JsonPath::Token(_)) => {
+                find_element(&mut jiter, element)?
+            }
+            (_, JsonPath::None) => return Err(GetError),
+            _ => Target::Unreachable,
+        }
+        .offset(value.start);
+    }
+    Ok(target)
+}
+
+/// Find the member `key` of the object `json`, a missing member would be added after the last member.
+fn find_member(json: &[u8], jiter: &mut Jiter, key: &str) -> Result<Target, GetError> {
+    let mut previous_end = None;
+    let mut opt_key = jiter.known_object()?;
+    while let Some(member_key) = opt_key {
+        if member_key == key {
+            let peek = jiter.peek()?;
+            let start = jiter.current_index();
+            jiter.known_skip(peek)?;
+            let end = jiter.current_index();
+            // the entry takes the comma before it, or the comma after it if it's the first member
+            let entry = if let Some(previous_end) = previous_end {
+                previous_end..end
+            } else if jiter.next_key()?.is_some() {
+                key_start(json, 1)..key_start(json, end)
+            } else {
+                key_start(json, 1)..end
+            };
+            return Ok(Target::Found {
+                value: start..end,
+                entry: Some(entry),
+            });
+        }
+        jiter.next_skip()?;
+        previous_end = Some(jiter.current_index());
+        opt_key = jiter.next_key()?;
+    }
+    let mut prefix = if previous_end.is_some() {
+        b",".to_vec()
+    } else {
+        Vec::new()
+    };
+    serde_json::to_writer(&mut prefix, key).map_err(|_| GetError)?;
+    prefix.push(b':');
+    Ok(Target::Missing {
+        at: previous_end.unwrap_or(1),
+        prefix,
+    })
+}
+
+/// The start of the next key in an object after `offset`, only whitespace and a comma can come before it.
+fn key_start(json: &[u8], offset: usize) -> usize {
+    json[offset..]
+        .iter()
+        .position(|&b| b == b'"')
+        .map_or(offset, |position| offset + position)
+}
+
+/// Find the element at `index` of the array at the start of `jiter`.
+///
+/// An index past the end of the array, or the JSON Pointer token `-`, would add an element after the last element.
+fn find_element(jiter: &mut Jiter, index: &JsonPath) -> Result<Target, GetError> {
+    let mut elements = Vec::new();
+    let mut opt_peek = jiter.known_array()?;
+    while let Some(peek) = opt_peek {
+        let start = jiter.current_index();
+        jiter.known_skip(peek)?;
+        elements.push(start..jiter.current_index());
+        opt_peek = jiter.array_step()?;
+    }
+    let index = match index {
+        JsonPath::IndexFromEnd(from_end) => match elements.len().checked_sub(*from_end) {
+            Some(index) => index,
+            None => return Ok(Target::Unreachable),
+        },
+        JsonPath::Token(token) if token == "-" => elements.len(),
+        index => match index.array_index() {
+            Some(index) => index,
+            None => return Ok(Target::Unreachable),
+        },
+    };
+    let Some(value) = elements.get(index) else {
+        return Ok(Target::Missing {
+            at: elements.last().map_or(1, |range| range.end),
+            prefix: if elements.is_empty() { Vec::new() } else { b",".to_vec() },
+        });
+    };
+    let entry = if index > 0 {
+        elements[index - 1].end..value.end
+    } else if let Some(next) = elements.get(1) {
+        value.start..next.start
+    } else {
+        value.clone()
+    };
+    Ok(Target::Found {
+        value: value.clone(),
+        entry: Some(entry),
+    })
+}
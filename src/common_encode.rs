@@ -0,0 +1,417 @@
+use std::io::Write;
+use std::ops::Range;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{
+    Array, ArrayRef, AsArray, BinaryArray, BooleanArray, Float64Array, GenericListArray, Int64Array, MapArray,
+    OffsetSizeTrait, StringArray, StringBuilder, StructArray, UInt64Array, UnionArray,
+};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::{ArrowNativeType, DataType, Field, FieldRef};
+use datafusion::common::{
+    exec_datafusion_err, exec_err, plan_err, DataFusionError, Result as DataFusionResult, ScalarValue,
+};
+use datafusion::logical_expr::ColumnarValue;
+
+use crate::common_union::{is_json_field, is_json_union, json_field_metadata, JsonUnionEncoder, JsonUnionValue};
+
+/// The return field of functions producing JSON text, a `Utf8` field marked as JSON.
+pub(crate) fn json_text_field(name: &str) -> FieldRef {
+    Arc::new(Field::new(name, DataType::Utf8, true).with_metadata(json_field_metadata()))
+}
+
+/// Check at planning time that values of `data_type` can be encoded as JSON.
+pub(crate) fn check_encodable(data_type: &DataType, fn_name: &str) -> DataFusionResult<()> {
+    if is_encodable(data_type) {
+        Ok(())
+    } else {
+        plan_err!("Unsupported type for '{fn_name}', {data_type} can't be encoded as JSON.")
+    }
+}
+
+fn is_encodable(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Null
+        | DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float16
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Utf8View
+        | DataType::Decimal32(..)
+        | DataType::Decimal64(..)
+        | DataType::Decimal128(..)
+        | DataType::Decimal256(..)
+        | DataType::Timestamp(..)
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Duration(_)
+        | DataType::Interval(_)
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_) => true,
+        DataType::Dictionary(_, value_type) => is_encodable(value_type),
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            is_encodable(field.data_type())
+        }
+        DataType::Struct(fields) => fields.iter().all(|field| is_encodable(field.data_type())),
+        DataType::Map(entries, _) => is_encodable(entries.data_type()),
+        other => is_json_union(other),
+    }
+}
+
+/// Encodes the values of a function argument as JSON, a scalar is encoded as the value of every row.
+pub(crate) struct ColumnEncoder {
+    encoder: ArrayEncoder,
+    scalar: bool,
+}
+
+impl ColumnEncoder {
+    /// Strings from a `field` marked as JSON, e.g. by `json_field_metadata`, are embedded as raw JSON rather than
+    /// quoted.
+    pub(crate) fn try_new(arg: &ColumnarValue, field: &Field) -> DataFusionResult<Self> {
+        let (array, scalar) = match arg {
+            ColumnarValue::Array(array) => (Arc::clone(array), false),
+            ColumnarValue::Scalar(scalar) => (scalar.to_array()?, true),
+        };
+        Ok(Self {
+            encoder: ArrayEncoder::try_new(&array, is_json_field(field))?,
+            scalar,
+        })
+    }
+
+    pub(crate) fn is_null(&self, row: usize) -> bool {
+        self.encoder.is_null(self.row(row))
+    }
+
+    pub(crate) fn encode(&self, row: usize, out: &mut Vec<u8>) -> DataFusionResult<()> {
+        self.encoder.encode(self.row(row), out)
+    }
+
+    /// Encode the value as an object key, numbers and booleans are converted to strings.
+    pub(crate) fn encode_key(&self, row: usize, out: &mut Vec<u8>) -> DataFusionResult<()> {
+        self.encoder.encode_key(self.row(row), out)
+    }
+
+    fn row(&self, row: usize) -> usize {
+        if self.scalar {
+            0
+        } else {
+            row
+        }
+    }
+}
+
+/// Encodes the values of an array as JSON, SQL nulls are encoded as `null`.
+///
+/// Decimals are encoded as numbers with their exact digits, temporal values as strings in the ISO 8601 format of
+/// Arrow's cast to `Utf8`, binary values as strings of lowercase hex digits, structs and maps as objects, and lists
+/// as arrays.
+enum ArrayEncoder {
+    Null,
+    Bool(BooleanArray),
+    Int(Int64Array),
+    UInt(UInt64Array),
+    Float(Float64Array),
+    Str(StringArray),
+    RawJson(StringArray),
+    Union(Box<JsonUnionEncoder>),
+    /// The dictionary, its normalized keys and an encoder for its values
+    Dictionary(ArrayRef, Vec<usize>, Box<ArrayEncoder>),
+    Binary(BinaryArray),
+    /// The struct, and the JSON encoded name and an encoder for each of its fields
+    Struct(StructArray, Vec<(Vec<u8>, ArrayEncoder)>),
+    /// The list, the range of values in each row and an encoder for the values
+    List(ArrayRef, Vec<Range<usize>>, Box<ArrayEncoder>),
+    /// The map and encoders for its keys and values
+    Map(MapArray, Box<ArrayEncoder>, Box<ArrayEncoder>),
+}
+
+impl ArrayEncoder {
+    fn try_new(array: &ArrayRef, raw_json: bool) -> DataFusionResult<Self> {
+        let encoder = match array.data_type() {
+            DataType::Null => Self::Null,
+            DataType::Boolean => Self::Bool(array.as_boolean().clone()),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                Self::Int(cast(array, &DataType::Int64)?.as_primitive().clone())
+            }
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                Self::UInt(cast(array, &DataType::UInt64)?.as_primitive().clone())
+            }
+            DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+                Self::Float(cast(array, &DataType::Float64)?.as_primitive().clone())
+            }
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+                let strings = cast(array, &DataType::Utf8)?.as_string::<i32>().clone();
+                if raw_json {
+                    Self::RawJson(strings)
+                } else {
+                    Self::Str(strings)
+                }
+            }
+            DataType::Dictionary(_, _) => {
+                let dict = array.as_any_dictionary();
+                let values = Self::try_new(dict.values(), raw_json)?;
+                Self::Dictionary(Arc::clone(array), dict.normalized_keys(), Box::new(values))
+            }
+            DataType::Decimal32(..) | DataType::Decimal64(..) | DataType::Decimal128(..) | DataType::Decimal256(..) => {
+                // the exact decimal text is a valid JSON number
+                Self::RawJson(cast(array, &DataType::Utf8)?.as_string::<i32>().clone())
+            }
+            DataType::Timestamp(..)
+            | DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Duration(_)
+            | DataType::Interval(_) => Self::Str(cast(array, &DataType::Utf8)?.as_string::<i32>().clone()),
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView | DataType::FixedSizeBinary(_) => {
+                Self::Binary(cast(array, &DataType::Binary)?.as_binary::<i32>().clone())
+            }
+            DataType::Struct(fields) => {
+                let struct_array = array.as_struct();
+                let fields = fields
+                    .iter()
+                    .zip(struct_array.columns())
+                    .map(|(field, column)| {
+                        let mut name = Vec::new();
+                        write_str(&mut name, field.name())?;
+                        Ok((name, Self::try_new(column, is_json_field(field))?))
+                    })
+                    .collect::<DataFusionResult<_>>()?;
+                Self::Struct(struct_array.clone(), fields)
+            }
+            DataType::List(field) => Self::list(array, field, array.as_list::<i32>())?,
+            DataType::LargeList(field) => Self::list(array, field, array.as_list::<i64>())?,
+            DataType::FixedSizeList(field, size) => {
+                let list = array.as_fixed_size_list();
+                let size = size.as_usize();
+                let ranges = (0..list.len())
+                    .map(|i| {
+                        let start = list.value_offset(i).as_usize();
+                        start..start + size
+                    })
+                    .collect();
+                let values = Self::try_new(list.values(), is_json_field(field))?;
+                Self::List(Arc::clone(array), ranges, Box::new(values))
+            }
+            DataType::Map(entries, _) => {
+                let map = array.as_map();
+                let raw_json_values = match entries.data_type() {
+                    DataType::Struct(fields) => fields.get(1).is_some_and(|field| is_json_field(field)),
+                    _ => false,
+                };
+                let keys = Self::try_new(map.keys(), false)?;
+                let values = Self::try_new(map.values(), raw_json_values)?;
+                Self::Map(map.clone(), Box::new(keys), Box::new(values))
+            }
+            data_type if is_json_union(data_type) => {
+                let Some(union) = array.as_any().downcast_ref::<UnionArray>() else {
+                    return exec_err!("Expected a UnionArray for the JSON union type");
+                };
+                let Some(encoder) = JsonUnionEncoder::from_union(union.clone()) else {
+                    return exec_err!("Expected the JSON union type");
+                };
+                Self::Union(Box::new(encoder))
+            }
+            data_type => return exec_err!("Unsupported type {data_type}, it can't be encoded as JSON."),
+        };
+        Ok(encoder)
+    }
+
+    fn list<O: OffsetSizeTrait>(array: &ArrayRef, field: &Field, list: &GenericListArray<O>) -> DataFusionResult<Self> {
+        let ranges = list
+            .value_offsets()
+            .windows(2)
+            .map(|offsets| offsets[0].as_usize()..offsets[1].as_usize())
+            .collect();
+        let values = Self::try_new(list.values(), is_json_field(field))?;
+        Ok(Self::List(Arc::clone(array), ranges, Box::new(values)))
+    }
+
+    fn is_null(&self, row: usize) -> bool {
+        match self {
+            Self::Null => true,
+            Self::Bool(array) => array.is_null(row),
+            Self::Int(array) => array.is_null(row),
+            Self::UInt(array) => array.is_null(row),
+            Self::Float(array) => array.is_null(row),
+            Self::Str(array) | Self::RawJson(array) => array.is_null(row),
+            Self::Union(encoder) => matches!(encoder.get_value(row), JsonUnionValue::JsonNull),
+            Self::Dictionary(dict, keys, values) => dict.is_null(row) || values.is_null(keys[row]),
+            Self::Binary(array) => array.is_null(row),
+            Self::Struct(array, _) => array.is_null(row),
+            Self::List(array, ..) => array.is_null(row),
+            Self::Map(array, ..) => array.is_null(row),
+        }
+    }
+
+    fn encode(&self, row: usize, out: &mut Vec<u8>) -> DataFusionResult<()> {
+        if self.is_null(row) {
+            out.extend_from_slice(b"null");
+            return Ok(());
+        }
+        match self {
+            Self::Null => out.extend_from_slice(b"null"),
+            Self::Bool(array) => write_bool(out, array.value(row)),
+            Self::Int(array) => write!(out, "{}", array.value(row)).map_err(encode_err)?,
+            Self::UInt(array) => write!(out, "{}", array.value(row)).map_err(encode_err)?,
+            Self::Float(array) => write_float(out, array.value(row))?,
+            Self::Str(array) => write_str(out, array.value(row))?,
+            Self::RawJson(array) => out.extend_from_slice(array.value(row).as_bytes()),
+            Self::Union(encoder) => match encoder.get_value(row) {
+                JsonUnionValue::JsonNull => out.extend_from_slice(b"null"),
+                JsonUnionValue::Bool(value) => write_bool(out, value),
+                JsonUnionValue::Int(value) => write!(out, "{value}").map_err(encode_err)?,
+                JsonUnionValue::Float(value) => write_float(out, value)?,
+                JsonUnionValue::Str(value) => write_str(out, value)?,
+                JsonUnionValue::Array(json) | JsonUnionValue::Object(json) | JsonUnionValue::BigInt(json) => {
+                    out.extend_from_slice(json.as_bytes());
+                }
+            },
+            Self::Dictionary(_, keys, values) => values.encode(keys[row], out)?,
+            Self::Binary(array) => write_hex(out, array.value(row)),
+            Self::Struct(_, fields) => {
+                out.push(b'{');
+                for (i, (name, field)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    out.extend_from_slice(name);
+                    out.push(b':');
+                    field.encode(row, out)?;
+                }
+                out.push(b'}');
+            }
+            Self::List(_, ranges, values) => {
+                out.push(b'[');
+                for (i, value_row) in ranges[row].clone().enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    values.encode(value_row, out)?;
+                }
+                out.push(b']');
+            }
+            Self::Map(map, keys, values) => {
+                let offsets = map.value_offsets();
+                out.push(b'{');
+                for (i, entry) in (offsets[row].as_usize()..offsets[row + 1].as_usize()).enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    keys.encode_key(entry, out)?;
+                    out.push(b':');
+                    values.encode(entry, out)?;
+                }
+                out.push(b'}');
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_key(&self, row: usize, out: &mut Vec<u8>) -> DataFusionResult<()> {
+        if self.is_null(row) {
+            return exec_err!("Object keys can't be null");
+        }
+        match self {
+            Self::Str(array) => write_str(out, array.value(row)),
+            Self::Bool(_) | Self::Int(_) | Self::UInt(_) | Self::Float(_) => {
+                // the JSON for these values never needs escaping
+                out.push(b'"');
+                self.encode(row, out)?;
+                out.push(b'"');
+                Ok(())
+            }
+            Self::Union(encoder) => match encoder.get_value(row) {
+                JsonUnionValue::Str(value) => write_str(out, value),
+                JsonUnionValue::Array(_) | JsonUnionValue::Object(_) => {
+                    exec_err!("Object keys must be strings, numbers or booleans")
+                }
+                _ => {
+                    out.push(b'"');
+                    self.encode(row, out)?;
+                    out.push(b'"');
+                    Ok(())
+                }
+            },
+            Self::Dictionary(_, keys, values) => values.encode_key(keys[row], out),
+            Self::Null | Self::RawJson(_) | Self::Binary(_) | Self::Struct(..) | Self::List(..) | Self::Map(..) => {
+                exec_err!("Object keys must be strings, numbers or booleans")
+            }
+        }
+    }
+}
+
+fn write_bool(out: &mut Vec<u8>, value: bool) {
+    out.extend_from_slice(if value { b"true" } else { b"false" });
+}
+
+/// JSON has no representation of infinite or NaN floats, they're encoded as `null`.
+fn write_float(out: &mut Vec<u8>, value: f64) -> DataFusionResult<()> {
+    if value.is_finite() {
+        serde_json::to_writer(out, &value).map_err(encode_err)
+    } else {
+        out.extend_from_slice(b"null");
+        Ok(())
+    }
+}
+
+fn write_str(out: &mut Vec<u8>, value: &str) -> DataFusionResult<()> {
+    serde_json::to_writer(out, value).map_err(encode_err)
+}
+
+fn write_hex(out: &mut Vec<u8>, value: &[u8]) {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    out.push(b'"');
+    for byte in value {
+        out.push(HEX_DIGITS[usize::from(byte >> 4)]);
+        out.push(HEX_DIGITS[usize::from(byte & 0xf)]);
+    }
+    out.push(b'"');
+}
+
+fn encode_err(e: impl std::fmt::Display) -> DataFusionError {
+    exec_datafusion_err!("Failed to encode JSON: {e}")
+}
+
+/// Build the JSON text for each row with `encode_row`, which returns `false` if the row is null instead.
+///
+/// The result is a scalar if every argument is.
+pub(crate) fn encode_rows(
+    args: &[ColumnarValue],
+    number_rows: usize,
+    mut encode_row: impl FnMut(usize, &mut Vec<u8>) -> DataFusionResult<bool>,
+) -> DataFusionResult<ColumnarValue> {
+    let scalar = args.iter().all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+    let rows = if scalar { 1 } else { number_rows };
+    let mut builder = StringBuilder::with_capacity(rows, 0);
+    let mut json = Vec::new();
+    for row in 0..rows {
+        json.clear();
+        if encode_row(row, &mut json)? {
+            builder.append_value(std::str::from_utf8(&json).map_err(encode_err)?);
+        } else {
+            builder.append_null();
+        }
+    }
+    let array = builder.finish();
+    if scalar {
+        let value = (!array.is_null(0)).then(|| array.value(0).to_string());
+        Ok(ColumnarValue::Scalar(ScalarValue::Utf8(value)))
+    } else {
+        Ok(ColumnarValue::Array(Arc::new(array)))
+    }
+}
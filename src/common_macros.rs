@@ -10,12 +10,16 @@
 /// * `expr_fn_name`: name of the `expr_fn` function to be created
 /// * `arg`: 0 or more named arguments for the function
 /// * `doc`: documentation string for the function
+/// * `udf_new`: optional expression constructing the [`ScalarUDFImpl`], `<$udf_impl>::default()` if omitted
 ///
 /// Copied mostly from, `/datafusion/functions-array/src/macros.rs`.
 ///
 /// [`ScalarUDFImpl`]: datafusion_expr::ScalarUDFImpl
 macro_rules! make_udf_function {
     ($udf_impl:ty, $expr_fn_name:ident, $($arg:ident)*, $doc:expr) => {
+        make_udf_function!($udf_impl, $expr_fn_name, $($arg)*, $doc, <$udf_impl>::default());
+    };
+    ($udf_impl:ty, $expr_fn_name:ident, $($arg:ident)*, $doc:expr, $udf_new:expr) => {
         paste::paste! {
             #[doc = $doc]
             #[must_use] pub fn $expr_fn_name($($arg: datafusion::logical_expr::Expr),*) -> datafusion::logical_expr::Expr {
@@ -36,9 +40,7 @@ macro_rules! make_udf_function {
             pub fn [< $expr_fn_name _udf >]() -> std::sync::Arc<datafusion::logical_expr::ScalarUDF> {
                 [< STATIC_ $expr_fn_name:upper >]
                     .get_or_init(|| {
-                        std::sync::Arc::new(datafusion::logical_expr::ScalarUDF::new_from_impl(
-                            <$udf_impl>::default(),
-                        ))
+                        std::sync::Arc::new(datafusion::logical_expr::ScalarUDF::new_from_impl($udf_new))
                     })
                     .clone()
             }
@@ -47,3 +49,37 @@ macro_rules! make_udf_function {
 }
 
 pub(crate) use make_udf_function;
+
+/// Creates external API `AggregateUDF` for an aggregate UDF, the counterpart of [`make_udf_function`].
+///
+/// Creates a singleton `AggregateUDF` of the `$udaf_impl` function, a function named `$expr_fn_name _udaf` which
+/// returns that function, and a function named `$expr_fn_name` which returns an `Expr` calling it.
+macro_rules! make_udaf_function {
+    ($udaf_impl:ty, $expr_fn_name:ident, $($arg:ident)*, $doc:expr) => {
+        paste::paste! {
+            #[doc = $doc]
+            #[must_use] pub fn $expr_fn_name($($arg: datafusion::logical_expr::Expr),*) -> datafusion::logical_expr::Expr {
+                [< $expr_fn_name _udaf >]().call(vec![$($arg),*])
+            }
+
+            /// Singleton instance of [`$udaf_impl`], ensures the UDAF is only created once
+            static [< STATIC_ $expr_fn_name:upper >]: std::sync::OnceLock<std::sync::Arc<datafusion::logical_expr::AggregateUDF>> =
+                std::sync::OnceLock::new();
+
+            /// `AggregateFunction` that returns an [`AggregateUDF`] for [`$udaf_impl`]
+            ///
+            /// [`AggregateUDF`]: datafusion::logical_expr::AggregateUDF
+            pub fn [< $expr_fn_name _udaf >]() -> std::sync::Arc<datafusion::logical_expr::AggregateUDF> {
+                [< STATIC_ $expr_fn_name:upper >]
+                    .get_or_init(|| {
+                        std::sync::Arc::new(datafusion::logical_expr::AggregateUDF::new_from_impl(
+                            <$udaf_impl>::default(),
+                        ))
+                    })
+                    .clone()
+            }
+        }
+    };
+}
+
+pub(crate) use make_udaf_function;
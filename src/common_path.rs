@@ -0,0 +1,681 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use datafusion::common::{exec_err, Result as DataFusionResult};
+
+use crate::common::JsonPath;
+
+/// Whether a string path argument should be compiled as a `JSONPath` expression rather than used as a key.
+///
+/// Only `$` on its own, or `$` followed by `.` or `[`, is recognised, so keys which merely start with a
+/// dollar sign (e.g. `$oid`) are still looked up literally.
+pub fn is_json_path(s: &str) -> bool {
+    s == "$" || s.starts_with("$.") || s.starts_with("$[")
+}
+
+/// Compile a `JSONPath` string like `$.user.addresses[0].city` into the equivalent sequence of
+/// [`JsonPath`] elements.
+///
+/// Supported syntax:
+/// * `$` - the root value, must come first
+/// * `.name` - object member, the name runs until the next `.` or `[`
+/// * `['name']` or `["name"]` - object member, a backslash escapes the following character
+/// * `[0]` - array element, `[-1]` is the last element
+/// * `[start:end]` - array slice, either bound may be omitted and negative bounds count from the end
+/// * `.*` or `[*]` - every member of an object or element of an array
+/// * `..name`, `..*` or `..[0]` - recursive descent, applies the following element to the current value and all
+///   its descendants
+/// * `[?(...)]` or `[?...]` - filter, every member of an object or element of an array for which the
+///   [`FilterExpr`] is true
+pub fn parse_json_path(path: &str) -> DataFusionResult<Vec<JsonPath<'_>>> {
+    PathParser::new(path, false).parse()
+}
+
+/// Compile a Postgres `jsonpath` like `$.items[*] ? (@.qty > 10)` into the equivalent sequence of
+/// [`JsonPath`] elements, as used by the `@?` operator.
+///
+/// The syntax is that of [`parse_json_path`], plus:
+/// * an optional leading `lax`, the only mode supported
+/// * whitespace between elements
+/// * `."name"` - object member with a double quoted name
+/// * `.**` - the current value and all its descendants
+/// * `[last]` - the last element of an array
+/// * `? (...)` - the current value if the [`FilterExpr`] is true for it, each element of an array
+///
+/// Unlike in Postgres, arrays aren't unwrapped by member accessors, `[*]` must be used explicitly.
+pub fn parse_postgres_json_path(path: &str) -> DataFusionResult<Vec<JsonPath<'_>>> {
+    let mut parser = PathParser::new(path, true);
+    parser.mode()?;
+    if !parser.eat('$') {
+        return parser.error("expected '$' at the start of the path");
+    }
+    parser.segments(false)
+}
+
+/// Compile a Postgres `jsonpath` predicate like `$.a == 1 && $.b[*] > 2` into a [`FilterExpr`] evaluated
+/// against the whole document, as used by the `@@` operator.
+///
+/// Operands are paths starting with `$` in the syntax of [`parse_postgres_json_path`], or literals.
+pub fn parse_postgres_json_predicate(predicate: &str) -> DataFusionResult<FilterExpr<'_>> {
+    let mut parser = PathParser::new(predicate, true);
+    parser.predicate = true;
+    parser.mode()?;
+    let expr = parser.filter_or()?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return parser.error("expected the end of the predicate");
+    }
+    Ok(expr)
+}
+
+/// Split an RFC 6901 JSON Pointer like `/a/b~1c/0` into its unescaped reference tokens, `None` if the pointer
+/// is invalid.
+///
+/// The empty pointer `""` refers to the whole document, so has no tokens.
+pub fn parse_json_pointer(pointer: &str) -> Option<Vec<Cow<'_, str>>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(unescape_pointer_token)
+        .collect()
+}
+
+fn unescape_pointer_token(token: &str) -> Option<Cow<'_, str>> {
+    if !token.contains('~') {
+        return Some(Cow::Borrowed(token));
+    }
+    let mut unescaped = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            match chars.next() {
+                Some('0') => unescaped.push('~'),
+                Some('1') => unescaped.push('/'),
+                _ => return None,
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(Cow::Owned(unescaped))
+}
+
+/// The array index a JSON Pointer reference token refers to, RFC 6901 doesn't allow signs or leading zeros.
+pub fn pointer_token_index(token: &str) -> Option<usize> {
+    if !token.bytes().all(|b| b.is_ascii_digit()) || (token.len() > 1 && token.starts_with('0')) {
+        return None;
+    }
+    token.parse().ok()
+}
+
+/// A filter predicate, evaluated against each member of an object or element of an array.
+///
+/// Within a filter, `@` is the value being tested, e.g. `@.key == 'user_id' && !@.deleted`.
+#[derive(Debug, Clone)]
+pub enum FilterExpr<'s> {
+    /// `@.path` on its own, true if the path exists
+    Exists(Vec<JsonPath<'s>>),
+    /// `@.path == literal` and other comparisons, true if any value matched by the path compares as
+    /// expected
+    Compare(Vec<JsonPath<'s>>, CompareOp, FilterLiteral<'s>),
+    And(Box<FilterExpr<'s>>, Box<FilterExpr<'s>>),
+    Or(Box<FilterExpr<'s>>, Box<FilterExpr<'s>>),
+    Not(Box<FilterExpr<'s>>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    /// The equivalent operator with the operands swapped, e.g. `1 < @.a` is `@.a > 1`.
+    fn flip(self) -> Self {
+        match self {
+            Self::Lt => Self::Gt,
+            Self::Le => Self::Ge,
+            Self::Gt => Self::Lt,
+            Self::Ge => Self::Le,
+            op => op,
+        }
+    }
+
+    /// Compare two scalar values, values of different types are never equal and only numbers and strings
+    /// can be ordered.
+    pub(crate) fn apply(self, left: &FilterLiteral, right: &FilterLiteral) -> bool {
+        let ordering = match (left, right) {
+            (FilterLiteral::Number(left), FilterLiteral::Number(right)) => left.partial_cmp(right),
+            (FilterLiteral::Str(left), FilterLiteral::Str(right)) => Some(left.cmp(right)),
+            _ => {
+                return match self {
+                    Self::Eq => left == right,
+                    Self::Ne => left != right,
+                    _ => false,
+                }
+            }
+        };
+        match self {
+            Self::Eq => ordering == Some(Ordering::Equal),
+            Self::Ne => ordering != Some(Ordering::Equal),
+            Self::Lt => ordering == Some(Ordering::Less),
+            Self::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            Self::Gt => ordering == Some(Ordering::Greater),
+            Self::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        }
+    }
+}
+
+/// A scalar value in a filter, either a literal in the path or a value from the JSON being filtered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterLiteral<'s> {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Str(Cow<'s, str>),
+}
+
+enum FilterOperand<'s> {
+    Path(Vec<JsonPath<'s>>),
+    Literal(FilterLiteral<'s>),
+}
+
+struct PathParser<'s> {
+    src: &'s str,
+    pos: usize,
+    /// Postgres `jsonpath` syntax
+    postgres: bool,
+    /// Parsing a Postgres predicate, where operands outside conditions start with `$` rather than `@`
+    predicate: bool,
+}
+
+impl<'s> PathParser<'s> {
+    fn new(src: &'s str, postgres: bool) -> Self {
+        Self {
+            src,
+            pos: 0,
+            postgres,
+            predicate: false,
+        }
+    }
+
+    fn parse(mut self) -> DataFusionResult<Vec<JsonPath<'s>>> {
+        if !self.eat('$') {
+            return self.error("expected '$' at the start of the path");
+        }
+        self.segments(false)
+    }
+
+    /// Skip the optional mode at the start of a Postgres path, strict mode isn't supported.
+    fn mode(&mut self) -> DataFusionResult<()> {
+        if self.eat_token("strict") {
+            return self.error("strict mode isn't supported");
+        }
+        self.eat_token("lax");
+        self.skip_whitespace();
+        Ok(())
+    }
+
+    /// Parse a sequence of `.` and `[` path elements.
+    ///
+    /// In a filter the sequence ends at the first other character, otherwise it must run to the end of the path.
+    fn segments(&mut self, in_filter: bool) -> DataFusionResult<Vec<JsonPath<'s>>> {
+        let mut path = Vec::new();
+        loop {
+            if self.postgres {
+                self.skip_whitespace();
+                if self.eat('?') {
+                    path.push(self.condition()?);
+                    continue;
+                }
+            }
+            let Some(c) = self.peek() else {
+                break;
+            };
+            match c {
+                '.' | '[' => self.pos += 1,
+                _ if in_filter => break,
+                _ => return self.error("expected '.' or '['"),
+            }
+            match c {
+                '.' if self.postgres && self.rest().starts_with("**") => {
+                    self.pos += 2;
+                    path.push(JsonPath::RecursiveDescent);
+                }
+                '.' if self.postgres && self.eat('"') => path.push(JsonPath::Key(self.quoted('"')?)),
+                '.' if self.eat('.') => {
+                    path.push(JsonPath::RecursiveDescent);
+                    // `..[0]` is handled by the next iteration of the loop
+                    if self.peek() != Some('[') {
+                        path.push(self.dot_member(in_filter)?);
+                    }
+                }
+                '.' => path.push(self.dot_member(in_filter)?),
+                _ => path.push(self.bracket()?),
+            }
+        }
+        Ok(path)
+    }
+
+    /// Parse a Postgres `? (...)` condition, the `?` has already been consumed.
+    fn condition(&mut self) -> DataFusionResult<JsonPath<'s>> {
+        if !self.eat_token("(") {
+            return self.error("expected '(' after '?'");
+        }
+        // within a condition `@` is the value being tested, even in a predicate
+        let predicate = std::mem::replace(&mut self.predicate, false);
+        let filter = self.filter_or();
+        self.predicate = predicate;
+        let filter = filter?;
+        if self.eat_token(")") {
+            Ok(JsonPath::Condition(Box::new(filter)))
+        } else {
+            self.error("expected ')'")
+        }
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn dot_member(&mut self, in_filter: bool) -> DataFusionResult<JsonPath<'s>> {
+        let rest = self.rest();
+        // in a filter or a Postgres path, names also end at whitespace and the characters of the filter syntax
+        let end = rest
+            .find(|c: char| {
+                matches!(c, '.' | '[')
+                    || ((in_filter || self.postgres) && (c.is_whitespace() || "()]=!<>&|".contains(c)))
+                    || (self.postgres && c == '?')
+            })
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return self.error("expected a member name after '.'");
+        }
+        self.pos += end;
+        match &rest[..end] {
+            "*" => Ok(JsonPath::Wildcard),
+            name => Ok(JsonPath::Key(Cow::Borrowed(name))),
+        }
+    }
+
+    fn bracket(&mut self) -> DataFusionResult<JsonPath<'s>> {
+        self.skip_whitespace();
+        let element = match self.peek() {
+            Some(quote @ ('\'' | '"')) => {
+                self.pos += 1;
+                JsonPath::Key(self.quoted(quote)?)
+            }
+            Some('0'..='9' | '-' | ':') => self.index_or_slice()?,
+            Some('l') if self.postgres && self.eat_token("last") => JsonPath::IndexFromEnd(1),
+            Some('*') => {
+                self.pos += 1;
+                JsonPath::Wildcard
+            }
+            Some('?') => {
+                self.pos += 1;
+                JsonPath::Filter(Box::new(self.filter_or()?))
+            }
+            _ => return self.error("expected a quoted member name or an array index"),
+        };
+        self.skip_whitespace();
+        if self.eat(']') {
+            Ok(element)
+        } else {
+            self.error("expected ']'")
+        }
+    }
+
+    /// Parse the remainder of a quoted member name, the opening quote has already been consumed.
+    ///
+    /// The common case of a name without escapes is borrowed from the path string.
+    fn quoted(&mut self, quote: char) -> DataFusionResult<Cow<'s, str>> {
+        let rest = self.rest();
+        let mut unescaped: Option<String> = None;
+        let mut chars = rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            if c == quote {
+                self.pos += i + 1;
+                return Ok(unescaped.map_or(Cow::Borrowed(&rest[..i]), Cow::Owned));
+            } else if c == '\\' {
+                let Some((_, escaped)) = chars.next() else {
+                    break;
+                };
+                unescaped.get_or_insert_with(|| rest[..i].to_string()).push(escaped);
+            } else if let Some(unescaped) = unescaped.as_mut() {
+                unescaped.push(c);
+            }
+        }
+        self.error("unterminated quoted member name")
+    }
+
+    fn filter_or(&mut self) -> DataFusionResult<FilterExpr<'s>> {
+        let mut expr = self.filter_and()?;
+        while self.eat_token("||") {
+            expr = FilterExpr::Or(Box::new(expr), Box::new(self.filter_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn filter_and(&mut self) -> DataFusionResult<FilterExpr<'s>> {
+        let mut expr = self.filter_unary()?;
+        while self.eat_token("&&") {
+            expr = FilterExpr::And(Box::new(expr), Box::new(self.filter_unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn filter_unary(&mut self) -> DataFusionResult<FilterExpr<'s>> {
+        if self.eat_token("!") {
+            return Ok(FilterExpr::Not(Box::new(self.filter_unary()?)));
+        }
+        if self.eat_token("(") {
+            let expr = self.filter_or()?;
+            return if self.eat_token(")") {
+                Ok(expr)
+            } else {
+                self.error("expected ')'")
+            };
+        }
+        let left = self.filter_operand()?;
+        let Some(op) = self.compare_op() else {
+            return match left {
+                FilterOperand::Path(path) => Ok(FilterExpr::Exists(path)),
+                FilterOperand::Literal(_) => self.error("expected a comparison operator"),
+            };
+        };
+        match (left, self.filter_operand()?) {
+            (FilterOperand::Path(path), FilterOperand::Literal(literal)) => Ok(FilterExpr::Compare(path, op, literal)),
+            (FilterOperand::Literal(literal), FilterOperand::Path(path)) => {
+                Ok(FilterExpr::Compare(path, op.flip(), literal))
+            }
+            _ => self.error("expected a comparison between '@' and a literal"),
+        }
+    }
+
+    fn filter_operand(&mut self) -> DataFusionResult<FilterOperand<'s>> {
+        self.skip_whitespace();
+        let root = if self.predicate { '$' } else { '@' };
+        let literal = match self.peek() {
+            Some(c) if c == root => {
+                self.pos += 1;
+                return Ok(FilterOperand::Path(self.segments(true)?));
+            }
+            Some(quote @ ('\'' | '"')) => {
+                self.pos += 1;
+                FilterLiteral::Str(self.quoted(quote)?)
+            }
+            Some('0'..='9' | '-') => {
+                let rest = self.rest();
+                let end = rest
+                    .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+                    .unwrap_or(rest.len());
+                let Ok(number) = rest[..end].parse() else {
+                    return self.error("invalid number");
+                };
+                self.pos += end;
+                FilterLiteral::Number(number)
+            }
+            _ if self.eat_token("true") => FilterLiteral::Bool(true),
+            _ if self.eat_token("false") => FilterLiteral::Bool(false),
+            _ if self.eat_token("null") => FilterLiteral::Null,
+            _ => return self.error(&format!("expected '{root}' or a literal")),
+        };
+        Ok(FilterOperand::Literal(literal))
+    }
+
+    fn compare_op(&mut self) -> Option<CompareOp> {
+        // two character operators must be checked first
+        [
+            ("==", CompareOp::Eq),
+            ("!=", CompareOp::Ne),
+            ("<>", CompareOp::Ne),
+            ("<=", CompareOp::Le),
+            (">=", CompareOp::Ge),
+            ("<", CompareOp::Lt),
+            (">", CompareOp::Gt),
+        ]
+        .into_iter()
+        .find_map(|(token, op)| self.eat_token(token).then_some(op))
+    }
+
+    /// Skip whitespace then consume `token` if it's next.
+    fn eat_token(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn index_or_slice(&mut self) -> DataFusionResult<JsonPath<'s>> {
+        let start = self.integer()?;
+        self.skip_whitespace();
+        if self.eat(':') {
+            self.skip_whitespace();
+            let end = self.integer()?;
+            Ok(JsonPath::Slice(start, end))
+        } else if let Some(index) = start {
+            Ok(JsonPath::from(index))
+        } else {
+            self.error("expected an array index")
+        }
+    }
+
+    /// Parse an optionally negative integer, `None` if there isn't one.
+    fn integer(&mut self) -> DataFusionResult<Option<i64>> {
+        let rest = self.rest();
+        let digits = rest.strip_prefix('-').unwrap_or(rest);
+        let digit_count = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+        if digit_count == 0 {
+            return if digits.len() < rest.len() {
+                self.error("expected digits after '-'")
+            } else {
+                Ok(None)
+            };
+        }
+        let end = rest.len() - digits.len() + digit_count;
+        let Ok(integer) = rest[..end].parse() else {
+            return self.error("array index out of range");
+        };
+        self.pos += end;
+        Ok(Some(integer))
+    }
+
+    fn error<T>(&self, message: &str) -> DataFusionResult<T> {
+        exec_err!("Invalid JSONPath '{}' at position {}: {message}", self.src, self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(path: &str) -> Vec<String> {
+        parse_json_path(path)
+            .unwrap()
+            .iter()
+            .map(|element| format!("{element:?}"))
+            .collect()
+    }
+
+    #[test]
+    fn detects_json_paths() {
+        assert!(is_json_path("$"));
+        assert!(is_json_path("$.a"));
+        assert!(is_json_path("$[0]"));
+        assert!(!is_json_path("$oid"));
+        assert!(!is_json_path("a.b"));
+    }
+
+    #[test]
+    fn parses_members_and_indices() {
+        assert_eq!(parse("$"), Vec::<String>::new());
+        assert_eq!(
+            parse("$.user.addresses[0].city"),
+            [r#"Key("user")"#, r#"Key("addresses")"#, "Index(0)", r#"Key("city")"#]
+        );
+        assert_eq!(parse("$[ 12 ]"), ["Index(12)"]);
+    }
+
+    #[test]
+    fn parses_quoted_members() {
+        assert_eq!(parse("$['a.b']"), [r#"Key("a.b")"#]);
+        assert_eq!(parse(r#"$["x"]['it\'s']"#), [r#"Key("x")"#, r#"Key("it's")"#]);
+    }
+
+    #[test]
+    fn parses_wildcards_and_descent() {
+        assert_eq!(
+            parse("$.items[*].price"),
+            [r#"Key("items")"#, "Wildcard", r#"Key("price")"#]
+        );
+        assert_eq!(parse("$.*"), ["Wildcard"]);
+        assert_eq!(parse("$..price"), ["RecursiveDescent", r#"Key("price")"#]);
+        assert_eq!(parse("$..*"), ["RecursiveDescent", "Wildcard"]);
+        assert_eq!(parse("$..[1]"), ["RecursiveDescent", "Index(1)"]);
+    }
+
+    #[test]
+    fn parses_negative_indices_and_slices() {
+        assert_eq!(parse("$[-1]"), ["IndexFromEnd(1)"]);
+        assert_eq!(parse("$[1:3]"), ["Slice(Some(1), Some(3))"]);
+        assert_eq!(parse("$[-2:]"), ["Slice(Some(-2), None)"]);
+        assert_eq!(parse("$[ : 2 ]"), ["Slice(None, Some(2))"]);
+        assert_eq!(parse("$[:]"), ["Slice(None, None)"]);
+    }
+
+    #[test]
+    fn parses_filters() {
+        assert_eq!(
+            parse("$.attributes[?(@.key == 'user_id')].value"),
+            [
+                r#"Key("attributes")"#,
+                r#"Filter(Compare([Key("key")], Eq, Str("user_id")))"#,
+                r#"Key("value")"#
+            ]
+        );
+        assert_eq!(
+            parse("$[?@.a>=1.5 && !(@['b'] || null != @.c[0])]"),
+            [concat!(
+                r#"Filter(And(Compare([Key("a")], Ge, Number(1.5)), "#,
+                r#"Not(Or(Exists([Key("b")]), Compare([Key("c"), Index(0)], Ne, Null)))))"#
+            )]
+        );
+        assert_eq!(parse("$[?(1 < @)]"), ["Filter(Compare([], Gt, Number(1.0)))"]);
+    }
+
+    #[test]
+    fn parses_postgres_paths() {
+        let parse_postgres = |path| -> Vec<String> {
+            parse_postgres_json_path(path)
+                .unwrap()
+                .iter()
+                .map(|element| format!("{element:?}"))
+                .collect()
+        };
+        assert_eq!(
+            parse_postgres("lax $.items[*] ? (@.qty > 10)"),
+            [
+                r#"Key("items")"#,
+                "Wildcard",
+                r#"Condition(Compare([Key("qty")], Gt, Number(10.0)))"#
+            ]
+        );
+        assert_eq!(
+            parse_postgres(r#"$.a.**."b c"[last]"#),
+            [r#"Key("a")"#, "RecursiveDescent", r#"Key("b c")"#, "IndexFromEnd(1)"]
+        );
+    }
+
+    #[test]
+    fn parses_postgres_predicates() {
+        let predicate = parse_postgres_json_predicate(r#"$.a == 1 && $.b[*] ? (@ <> "x")"#).unwrap();
+        assert_eq!(
+            format!("{predicate:?}"),
+            concat!(
+                r#"And(Compare([Key("a")], Eq, Number(1.0)), "#,
+                r#"Exists([Key("b"), Wildcard, Condition(Compare([], Ne, Str("x")))]))"#
+            )
+        );
+        for predicate in ["@.a == 1", "$.a ? ($.b == 1)", "$.a == 1 $.b", "strict $.a == 1"] {
+            assert!(
+                parse_postgres_json_predicate(predicate).is_err(),
+                "{predicate} should be invalid"
+            );
+        }
+        for path in ["$.a ? @.b", "$.a ? (@.b", "$.a == 1"] {
+            assert!(parse_postgres_json_path(path).is_err(), "{path} should be invalid");
+        }
+    }
+
+    #[test]
+    fn parses_json_pointers() {
+        assert_eq!(parse_json_pointer(""), Some(vec![]));
+        assert_eq!(
+            parse_json_pointer("/a/b~1c/0"),
+            Some(vec!["a".into(), "b/c".into(), "0".into()])
+        );
+        assert_eq!(
+            parse_json_pointer("/~01//"),
+            Some(vec!["~1".into(), "".into(), "".into()])
+        );
+        assert_eq!(parse_json_pointer("a/b"), None);
+        assert_eq!(parse_json_pointer("/a~2"), None);
+
+        assert_eq!(pointer_token_index("0"), Some(0));
+        assert_eq!(pointer_token_index("12"), Some(12));
+        for token in ["", "-", "01", "-1", "+1", "a"] {
+            assert_eq!(pointer_token_index(token), None, "{token:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_paths() {
+        for path in [
+            "a",
+            "$.",
+            "$..",
+            "$...a",
+            "$[",
+            "$['a'",
+            "$[a]",
+            "$x",
+            "$[0",
+            "$[-]",
+            "$[1:2:3]",
+            "$[?(@.a == )]",
+            "$[?(1 == 2)]",
+            "$[?(@.a]",
+            "$[?('a')]",
+        ] {
+            assert!(parse_json_path(path).is_err(), "{path} should be invalid");
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::{DataType, Field, Fields, TimeUnit, DECIMAL256_MAX_PRECISION};
+use datafusion::common::{plan_err, Result as DataFusionResult};
+
+use crate::json_get_decimal::decimal_type;
+
+/// Parse a Spark style schema into the Arrow type it describes.
+///
+/// The schema is either a list of fields, e.g. `a INT, b STRUCT<c: STRING>, d ARRAY<DOUBLE>` which is a struct,
+/// or a single `STRUCT<...>`, `ARRAY<...>` or `MAP<STRING, ...>` type. Type names are case insensitive and
+/// field names can be quoted with backticks.
+pub fn parse_schema(schema: &str) -> DataFusionResult<DataType> {
+    let mut parser = SchemaParser { src: schema, pos: 0 };
+    parser.skip_whitespace();
+    let upper = parser.rest().to_ascii_uppercase();
+    let data_type = if ["STRUCT", "ARRAY", "MAP"].iter().any(|name| {
+        upper
+            .strip_prefix(name)
+            .is_some_and(|rest| rest.trim_start().starts_with('<'))
+    }) {
+        parser.data_type()?
+    } else {
+        DataType::Struct(parser.fields(None)?)
+    };
+    parser.skip_whitespace();
+    if parser.pos < schema.len() {
+        return parser.error("unexpected trailing characters");
+    }
+    Ok(data_type)
+}
+
+struct SchemaParser<'s> {
+    src: &'s str,
+    pos: usize,
+}
+
+impl<'s> SchemaParser<'s> {
+    /// Parse comma separated `name [:] type` fields, up to `end` or the end of the schema.
+    fn fields(&mut self, end: Option<char>) -> DataFusionResult<Fields> {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let name = self.name()?;
+            self.skip_whitespace();
+            self.eat(':');
+            let data_type = self.data_type()?;
+            fields.push(Field::new(name, data_type, true));
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+        }
+        if let Some(end) = end {
+            self.expect(end)?;
+        }
+        Ok(fields.into())
+    }
+
+    fn data_type(&mut self) -> DataFusionResult<DataType> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let word = self.word().to_ascii_uppercase();
+        let data_type = match word.as_str() {
+            "BOOLEAN" | "BOOL" => DataType::Boolean,
+            "TINYINT" | "BYTE" => DataType::Int8,
+            "SMALLINT" | "SHORT" => DataType::Int16,
+            "INT" | "INTEGER" => DataType::Int32,
+            "BIGINT" | "LONG" => DataType::Int64,
+            "FLOAT" | "REAL" => DataType::Float32,
+            "DOUBLE" => DataType::Float64,
+            "STRING" | "VARCHAR" | "TEXT" => DataType::Utf8,
+            "DECIMAL" | "DEC" | "NUMERIC" => self.decimal()?,
+            "DATE" => DataType::Date32,
+            "TIMESTAMP" => DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+            "BINARY" => DataType::Binary,
+            "ARRAY" => {
+                self.expect('<')?;
+                let item = self.data_type()?;
+                self.expect('>')?;
+                DataType::List(Arc::new(Field::new_list_field(item, true)))
+            }
+            "MAP" => {
+                self.expect('<')?;
+                if self.data_type()? != DataType::Utf8 {
+                    return self.error("map keys must be strings");
+                }
+                self.expect(',')?;
+                let value = self.data_type()?;
+                self.expect('>')?;
+                map_type(value)
+            }
+            "STRUCT" => {
+                self.expect('<')?;
+                DataType::Struct(self.fields(Some('>'))?)
+            }
+            _ => {
+                self.pos = start;
+                return self.error("expected a type");
+            }
+        };
+        Ok(data_type)
+    }
+
+    /// The optional `(precision[, scale])` of a decimal, the precision defaults to 10 and the scale to 0 like Spark.
+    fn decimal(&mut self) -> DataFusionResult<DataType> {
+        self.skip_whitespace();
+        let (precision, scale) = if self.eat('(') {
+            let precision = self.number()?;
+            self.skip_whitespace();
+            let scale = if self.eat(',') { self.number()? } else { 0 };
+            self.expect(')')?;
+            (precision, scale)
+        } else {
+            (10, 0)
+        };
+        match (u8::try_from(precision), i8::try_from(scale)) {
+            (Ok(precision @ 1..=DECIMAL256_MAX_PRECISION), Ok(scale)) if i16::from(scale) <= i16::from(precision) => {
+                Ok(decimal_type(precision, scale))
+            }
+            _ => self.error(&format!("invalid decimal precision {precision} and scale {scale}")),
+        }
+    }
+
+    fn number(&mut self) -> DataFusionResult<u32> {
+        self.skip_whitespace();
+        match self.word().parse() {
+            Ok(number) => Ok(number),
+            Err(_) => self.error("expected a number"),
+        }
+    }
+
+    /// A field name, either a word or quoted with backticks.
+    fn name(&mut self) -> DataFusionResult<String> {
+        if self.eat('`') {
+            let rest = self.rest();
+            let Some(end) = rest.find('`') else {
+                return self.error("unterminated quoted field name");
+            };
+            self.pos += end + 1;
+            Ok(rest[..end].to_string())
+        } else {
+            match self.word() {
+                "" => self.error("expected a field name"),
+                word => Ok(word.to_string()),
+            }
+        }
+    }
+
+    fn word(&mut self) -> &'s str {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        self.pos += end;
+        &rest[..end]
+    }
+
+    fn expect(&mut self, c: char) -> DataFusionResult<()> {
+        self.skip_whitespace();
+        if self.eat(c) {
+            Ok(())
+        } else {
+            self.error(&format!("expected '{c}'"))
+        }
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn error<T>(&self, message: &str) -> DataFusionResult<T> {
+        plan_err!("Invalid schema '{}' at position {}: {message}", self.src, self.pos)
+    }
+}
+
+/// The Arrow map type with string keys and `value` values.
+pub fn map_type(value: DataType) -> DataType {
+    let entries = Fields::from(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", value, true),
+    ]);
+    DataType::Map(Arc::new(Field::new("entries", DataType::Struct(entries), false)), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(item: DataType) -> DataType {
+        DataType::List(Arc::new(Field::new_list_field(item, true)))
+    }
+
+    #[test]
+    fn parses_field_lists() {
+        assert_eq!(
+            parse_schema("a INT, b STRUCT<c: STRING>, d ARRAY<DOUBLE>").unwrap(),
+            DataType::Struct(Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new(
+                    "b",
+                    DataType::Struct(Fields::from(vec![Field::new("c", DataType::Utf8, true)])),
+                    true
+                ),
+                Field::new("d", list(DataType::Float64), true),
+            ]))
+        );
+        assert_eq!(
+            parse_schema("`a b`: bigint,c boolean").unwrap(),
+            DataType::Struct(Fields::from(vec![
+                Field::new("a b", DataType::Int64, true),
+                Field::new("c", DataType::Boolean, true),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parses_single_types() {
+        assert_eq!(parse_schema("array<int>").unwrap(), list(DataType::Int32));
+        assert_eq!(
+            parse_schema(" MAP<STRING, ARRAY<STRING>> ").unwrap(),
+            map_type(list(DataType::Utf8))
+        );
+        assert_eq!(
+            parse_schema("struct<x float>").unwrap(),
+            DataType::Struct(Fields::from(vec![Field::new("x", DataType::Float32, true)]))
+        );
+    }
+
+    #[test]
+    fn parses_decimal_temporal_and_binary_types() {
+        assert_eq!(
+            parse_schema("a DECIMAL, b decimal(5), c DECIMAL(40, 2), d DATE, e TIMESTAMP, f BINARY").unwrap(),
+            DataType::Struct(Fields::from(vec![
+                Field::new("a", DataType::Decimal128(10, 0), true),
+                Field::new("b", DataType::Decimal128(5, 0), true),
+                Field::new("c", DataType::Decimal256(40, 2), true),
+                Field::new("d", DataType::Date32, true),
+                Field::new("e", DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())), true),
+                Field::new("f", DataType::Binary, true),
+            ]))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schemas() {
+        for schema in [
+            "",
+            "a",
+            "a INT,",
+            "a DATE(1)",
+            "a DECIMAL(0)",
+            "a DECIMAL(10, 11)",
+            "a DECIMAL(x)",
+            "a ARRAY<INT",
+            "MAP<INT, INT>",
+            "`a INT",
+            "a INT b",
+        ] {
+            assert!(parse_schema(schema).is_err(), "{schema}");
+        }
+        assert_eq!(
+            parse_schema("a INT, b FOO").unwrap_err().to_string(),
+            "Error during planning: Invalid schema 'a INT, b FOO' at position 9: expected a type"
+        );
+    }
+}
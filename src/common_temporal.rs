@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone};
+use datafusion::arrow::array::timezone::Tz;
+use datafusion::arrow::compute::kernels::cast_utils::string_to_datetime;
+use datafusion::arrow::datatypes::TimeUnit;
+use datafusion::common::{plan_err, Result as DataFusionResult, ScalarValue};
+use jiter::{Jiter, JiterError, NumberAny, NumberInt, Peek};
+
+use crate::common::{get_err, jiter_json_find, GetError, JsonPath};
+
+/// Options for the temporal functions, given by the `_with` variants as arguments right after the JSON argument so
+/// they can't be mistaken for path elements, e.g.
+/// `json_get_timestamp_with(json, '%d/%m/%Y %H:%M', NULL, 'Europe/London', 'ts')`.
+#[derive(Debug)]
+pub(crate) struct TemporalOptions {
+    /// A `chrono` `strftime` format used to parse strings, instead of the default formats
+    pub format: Option<String>,
+    /// The unit of numeric values, which are an offset from the UNIX epoch (or midnight for times)
+    pub unit: TimeUnit,
+    /// The timezone of the output, and of strings without an offset
+    pub tz: Option<String>,
+}
+
+impl Default for TemporalOptions {
+    fn default() -> Self {
+        Self {
+            format: None,
+            unit: TimeUnit::Second,
+            tz: None,
+        }
+    }
+}
+
+/// The options of the plain temporal functions, which take none
+pub(crate) const NO_OPTIONS: &[&str] = &[];
+
+/// Read the options named by `names` from the arguments after the JSON argument, null options keep their default.
+pub(crate) fn temporal_options(
+    fn_name: &str,
+    scalar_args: &[Option<&ScalarValue>],
+    names: &[&str],
+) -> DataFusionResult<TemporalOptions> {
+    let mut options = TemporalOptions::default();
+    if scalar_args.len() <= names.len() {
+        return plan_err!(
+            "The '{fn_name}' function requires a JSON argument, the options ({}), then an optional path.",
+            names.join(", ")
+        );
+    }
+    for (&name, arg) in names.iter().zip(&scalar_args[1..]) {
+        let value = match arg {
+            Some(ScalarValue::Utf8(Some(s)) | ScalarValue::Utf8View(Some(s)) | ScalarValue::LargeUtf8(Some(s))) => s,
+            Some(scalar) if scalar.is_null() => continue,
+            _ => return plan_err!("The '{name}' option of '{fn_name}' must be a string literal or null."),
+        };
+        match name {
+            "format" => options.format = Some(value.clone()),
+            "unit" => {
+                options.unit = match value.as_str() {
+                    "s" => TimeUnit::Second,
+                    "ms" => TimeUnit::Millisecond,
+                    "us" => TimeUnit::Microsecond,
+                    "ns" => TimeUnit::Nanosecond,
+                    _ => return plan_err!("Unknown unit '{value}' for '{fn_name}', expected 's', 'ms', 'us' or 'ns'."),
+                };
+            }
+            _ => {
+                if Tz::from_str(value).is_err() {
+                    return plan_err!("Unknown timezone '{value}' for '{fn_name}'.");
+                }
+                options.tz = Some(value.clone());
+            }
+        }
+    }
+    Ok(options)
+}
+
+/// A JSON value which can be converted to a date or time.
+pub(crate) enum TemporalValue<'a> {
+    Str(&'a str),
+    Int(i64),
+    Float(f64),
+}
+
+/// Find the value at `path`, and convert it with `convert` if it's a string or number.
+pub(crate) fn jiter_json_get_temporal<T>(
+    json_data: Option<&str>,
+    path: &[JsonPath],
+    convert: impl FnOnce(TemporalValue) -> Option<T>,
+) -> Result<T, GetError> {
+    let Some((mut jiter, peek)) = jiter_json_find(json_data, path) else {
+        return get_err!();
+    };
+    let Some(value) = read_temporal(&mut jiter, peek)? else {
+        return get_err!();
+    };
+    convert(value).ok_or(GetError)
+}
+
+/// Read the value starting at `peek` if it's a string or number, other values are skipped.
+pub(crate) fn read_temporal<'j>(jiter: &'j mut Jiter, peek: Peek) -> Result<Option<TemporalValue<'j>>, JiterError> {
+    let value = match peek {
+        Peek::String => TemporalValue::Str(jiter.known_str()?),
+        _ if peek.is_num() => match jiter.known_number(peek)? {
+            NumberAny::Int(NumberInt::Int(i)) => TemporalValue::Int(i),
+            NumberAny::Int(NumberInt::BigInt(_)) => return Ok(None),
+            NumberAny::Float(f) => TemporalValue::Float(f),
+        },
+        _ => {
+            jiter.known_skip(peek)?;
+            return Ok(None);
+        }
+    };
+    Ok(Some(value))
+}
+
+/// Convert a number in `unit` to nanoseconds, strings of digits are treated as numbers.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+pub(crate) fn number_to_nanos(value: &TemporalValue, unit: TimeUnit) -> Option<i64> {
+    let nanos_per_unit: i64 = match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+    match value {
+        TemporalValue::Int(i) => i.checked_mul(nanos_per_unit),
+        TemporalValue::Float(f) => {
+            let nanos = (f * nanos_per_unit as f64).round();
+            (nanos.is_finite() && nanos >= i64::MIN as f64 && nanos < i64::MAX as f64).then_some(nanos as i64)
+        }
+        TemporalValue::Str(s) => s.parse::<i64>().ok()?.checked_mul(nanos_per_unit),
+    }
+}
+
+/// Parse a timestamp string to nanoseconds since the UNIX epoch.
+///
+/// Without a format, RFC 3339 and the similar formats accepted by arrow's casts are used. Strings without an offset
+/// are in `tz`, or UTC if there's no timezone.
+pub(crate) fn parse_timestamp_nanos(s: &str, format: Option<&str>, tz: Option<&str>) -> Option<i64> {
+    let tz = Tz::from_str(tz.unwrap_or("UTC")).ok()?;
+    let Some(format) = format else {
+        return string_to_datetime(&tz, s).ok()?.timestamp_nanos_opt();
+    };
+    if let Ok(datetime) = DateTime::parse_from_str(s, format) {
+        return datetime.timestamp_nanos_opt();
+    }
+    let naive = NaiveDateTime::parse_from_str(s, format)
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(s, format).ok()?.and_hms_opt(0, 0, 0))?;
+    tz.from_local_datetime(&naive).single()?.timestamp_nanos_opt()
+}
@@ -33,6 +33,16 @@ pub fn json_field_metadata() -> HashMap<String, String> {
     ])
 }
 
+/// Whether a field is marked as containing raw JSON, by [`json_field_metadata`] or another producer of the
+/// canonical JSON extension type.
+pub(crate) fn is_json_field(field: &Field) -> bool {
+    let metadata = field.metadata();
+    metadata
+        .get("ARROW:extension:name")
+        .is_some_and(|name| name == "arrow.json")
+        || metadata.get("is_json").is_some_and(|is_json| is_json == "true")
+}
+
 pub fn is_json_union(data_type: &DataType) -> bool {
     match data_type {
         DataType::Union(fields, UnionMode::Sparse) => fields == &union_fields(),
@@ -56,6 +66,25 @@ pub(crate) fn nested_json_array_ref(array: &ArrayRef, object_lookup: bool) -> Op
     Some(union_array.child(type_id))
 }
 
+/// The nested JSON (objects and arrays) from each row of a `JsonUnion` `UnionArray`, other values are `None`.
+pub(crate) fn nested_json_values(array: &ArrayRef) -> Option<Vec<Option<&str>>> {
+    let union_array: &UnionArray = array.as_any().downcast_ref::<UnionArray>()?;
+    let objects = union_array.child(TYPE_ID_OBJECT).as_string::<i32>();
+    let arrays = union_array.child(TYPE_ID_ARRAY).as_string::<i32>();
+    Some(
+        union_array
+            .type_ids()
+            .iter()
+            .enumerate()
+            .map(|(i, type_id)| match *type_id {
+                TYPE_ID_OBJECT => Some(objects.value(i)),
+                TYPE_ID_ARRAY => Some(arrays.value(i)),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
 /// Extract a JSON string from a `JsonUnion` scalar
 pub(crate) fn json_from_union_scalar<'a>(
     type_id_value: Option<&'a (i8, Box<ScalarValue>)>,
@@ -82,6 +111,7 @@ pub(crate) struct JsonUnion {
     strings: Vec<Option<String>>,
     arrays: Vec<Option<String>>,
     objects: Vec<Option<String>>,
+    bigints: Vec<Option<String>>,
     type_ids: Vec<i8>,
     index: usize,
     length: usize,
@@ -96,6 +126,7 @@ impl JsonUnion {
             strings: vec![None; length],
             arrays: vec![None; length],
             objects: vec![None; length],
+            bigints: vec![None; length],
             type_ids: vec![TYPE_ID_NULL; length],
             index: 0,
             length,
@@ -116,6 +147,7 @@ impl JsonUnion {
             JsonUnionField::Str(value) => self.strings[self.index] = Some(value),
             JsonUnionField::Array(value) => self.arrays[self.index] = Some(value),
             JsonUnionField::Object(value) => self.objects[self.index] = Some(value),
+            JsonUnionField::BigInt(value) => self.bigints[self.index] = Some(value),
         }
         self.index += 1;
         debug_assert!(self.index <= self.length);
@@ -157,6 +189,7 @@ impl TryFrom<JsonUnion> for UnionArray {
             Arc::new(StringArray::from(value.strings)),
             Arc::new(StringArray::from(value.arrays)),
             Arc::new(StringArray::from(value.objects)),
+            Arc::new(StringArray::from(value.bigints)),
         ];
         UnionArray::try_new(union_fields(), Buffer::from_vec(value.type_ids).into(), None, children)
     }
@@ -171,6 +204,8 @@ pub(crate) enum JsonUnionField {
     Str(String),
     Array(String),
     Object(String),
+    /// An integer outside the range of `i64`, as its decimal text
+    BigInt(String),
 }
 
 pub(crate) const TYPE_ID_NULL: i8 = 0;
@@ -180,6 +215,7 @@ const TYPE_ID_FLOAT: i8 = 3;
 const TYPE_ID_STR: i8 = 4;
 const TYPE_ID_ARRAY: i8 = 5;
 const TYPE_ID_OBJECT: i8 = 6;
+const TYPE_ID_BIGINT: i8 = 7;
 
 fn union_fields() -> UnionFields {
     static FIELDS: OnceLock<UnionFields> = OnceLock::new();
@@ -199,6 +235,7 @@ fn union_fields() -> UnionFields {
                     TYPE_ID_OBJECT,
                     Arc::new(Field::new("object", DataType::Utf8, false).with_metadata(json_field_metadata())),
                 ),
+                (TYPE_ID_BIGINT, Arc::new(Field::new("bigint", DataType::Utf8, false))),
             ])
         })
         .clone()
@@ -214,6 +251,7 @@ impl JsonUnionField {
             Self::Str(_) => TYPE_ID_STR,
             Self::Array(_) => TYPE_ID_ARRAY,
             Self::Object(_) => TYPE_ID_OBJECT,
+            Self::BigInt(_) => TYPE_ID_BIGINT,
         }
     }
 
@@ -233,7 +271,10 @@ impl From<JsonUnionField> for ScalarValue {
             JsonUnionField::Bool(b) => Self::Boolean(Some(b)),
             JsonUnionField::Int(i) => Self::Int64(Some(i)),
             JsonUnionField::Float(f) => Self::Float64(Some(f)),
-            JsonUnionField::Str(s) | JsonUnionField::Array(s) | JsonUnionField::Object(s) => Self::Utf8(Some(s)),
+            JsonUnionField::Str(s)
+            | JsonUnionField::Array(s)
+            | JsonUnionField::Object(s)
+            | JsonUnionField::BigInt(s) => Self::Utf8(Some(s)),
         }
     }
 }
@@ -245,6 +286,7 @@ pub struct JsonUnionEncoder {
     string: StringArray,
     array: StringArray,
     object: StringArray,
+    bigint: StringArray,
     type_ids: ScalarBuffer<i8>,
 }
 
@@ -260,6 +302,7 @@ impl JsonUnionEncoder {
                 string: c[4].as_string().clone(),
                 array: c[5].as_string().clone(),
                 object: c[6].as_string().clone(),
+                bigint: c[7].as_string().clone(),
                 type_ids,
             })
         } else {
@@ -289,6 +332,7 @@ impl JsonUnionEncoder {
             TYPE_ID_STR => JsonUnionValue::Str(self.string.value(idx)),
             TYPE_ID_ARRAY => JsonUnionValue::Array(self.array.value(idx)),
             TYPE_ID_OBJECT => JsonUnionValue::Object(self.object.value(idx)),
+            TYPE_ID_BIGINT => JsonUnionValue::BigInt(self.bigint.value(idx)),
             _ => panic!("Invalid type_id: {type_id}, not a valid JSON type"),
         }
     }
@@ -303,6 +347,8 @@ pub enum JsonUnionValue<'a> {
     Str(&'a str),
     Array(&'a str),
     Object(&'a str),
+    /// An integer outside the range of `i64`, as its decimal text
+    BigInt(&'a str),
 }
 
 #[cfg(test)]
@@ -320,6 +366,7 @@ mod test {
             Some(JsonUnionField::Str("foo".to_string())),
             Some(JsonUnionField::Array("[42]".to_string())),
             Some(JsonUnionField::Object(r#"{"foo": 42}"#.to_string())),
+            Some(JsonUnionField::BigInt("18446744073709551616".to_string())),
             None,
         ]);
 
@@ -338,6 +385,7 @@ mod test {
                 JsonUnionValue::Str("foo"),
                 JsonUnionValue::Array("[42]"),
                 JsonUnionValue::Object(r#"{"foo": 42}"#),
+                JsonUnionValue::BigInt("18446744073709551616"),
                 JsonUnionValue::JsonNull,
             ]
         );
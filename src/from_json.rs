@@ -0,0 +1,595 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{
+    ArrayBuilder, ArrayRef, ArrowPrimitiveType, BinaryBuilder, BooleanBuilder, Decimal128Builder, Decimal256Builder,
+    ListArray, MapArray, NullBufferBuilder, PrimitiveBuilder, StringBuilder, StructArray,
+};
+use datafusion::arrow::buffer::{OffsetBuffer, ScalarBuffer};
+use datafusion::arrow::datatypes::{
+    DataType, Date32Type, DecimalType, Field, FieldRef, Fields, Float32Type, Float64Type, Int16Type, Int32Type,
+    Int64Type, Int8Type, TimeUnit, TimestampNanosecondType,
+};
+use datafusion::common::{exec_err, internal_err, plan_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use jiter::{Jiter, JiterError, NumberAny, NumberInt, Peek};
+
+use crate::common::{json_values, map_dictionary_values, return_type_check};
+use crate::common_macros::make_udf_function;
+use crate::common_schema::parse_schema;
+use crate::common_temporal::{read_temporal, TemporalOptions};
+use crate::json_get_date::date_days;
+use crate::json_get_decimal::{parse_decimal_rounded, read_decimal_text};
+use crate::json_get_timestamp::timestamp_nanos;
+
+make_udf_function!(
+    FromJson,
+    from_json,
+    json_data schema,
+    r"Parse a JSON string into the Arrow type described by a schema, e.g. `a INT, b ARRAY<STRING>`"
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct FromJson {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for FromJson {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["from_json".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for FromJson {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let [_, schema] = args.scalar_arguments else {
+            return plan_err!(
+                "The '{}' function requires two arguments, a JSON string and a schema.",
+                self.name()
+            );
+        };
+        let Some(
+            ScalarValue::Utf8(Some(schema))
+            | ScalarValue::Utf8View(Some(schema))
+            | ScalarValue::LargeUtf8(Some(schema)),
+        ) = schema
+        else {
+            return plan_err!(
+                "The second argument to '{}' must be a literal schema string.",
+                self.name()
+            );
+        };
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        let return_type = return_type_check(&arg_types, self.name(), parse_schema(schema)?)?;
+        Ok(Arc::new(Field::new(self.name(), return_type, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let [json_arg, ColumnarValue::Scalar(
+            ScalarValue::Utf8(Some(schema))
+            | ScalarValue::Utf8View(Some(schema))
+            | ScalarValue::LargeUtf8(Some(schema)),
+        )] = args.args.as_slice()
+        else {
+            return exec_err!(
+                "The '{}' function requires a JSON string and a literal schema.",
+                self.name()
+            );
+        };
+        let data_type = parse_schema(schema)?;
+
+        match json_arg {
+            ColumnarValue::Array(json_array) => {
+                map_dictionary_values(json_array, |json_array| decode_array(json_array, &data_type))
+                    .map(ColumnarValue::Array)
+            }
+            ColumnarValue::Scalar(scalar) => {
+                let array =
+                    map_dictionary_values(&scalar.to_array()?, |json_array| decode_array(json_array, &data_type))?;
+                ScalarValue::try_from_array(&array, 0).map(ColumnarValue::Scalar)
+            }
+        }
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the schema is a literal
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+fn decode_array(json_array: &ArrayRef, data_type: &DataType) -> DataFusionResult<ArrayRef> {
+    let json_values = json_values(json_array)?;
+    let mut builder = ValueBuilder::try_new(data_type, json_values.len())?;
+    for opt_json in json_values {
+        let Some(json) = opt_json else {
+            builder.append_null();
+            continue;
+        };
+        let mut jiter = Jiter::new(json.as_bytes());
+        match jiter.peek() {
+            // the builder appends null if the JSON is invalid part way through
+            Ok(peek) => builder.append_value(&mut jiter, peek).unwrap_or_default(),
+            Err(_) => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// Builds an array of `from_json` values, decoding each value directly from JSON.
+///
+/// Values which don't match the type are null, except for strings, where any other JSON value is kept as
+/// its JSON text. Decimals, dates and timestamps are decoded like `json_get_decimal`, `json_get_date` and
+/// `json_get_timestamp` without options.
+enum ValueBuilder {
+    Bool(BooleanBuilder),
+    Int8(PrimitiveBuilder<Int8Type>),
+    Int16(PrimitiveBuilder<Int16Type>),
+    Int32(PrimitiveBuilder<Int32Type>),
+    Int64(PrimitiveBuilder<Int64Type>),
+    Float32(PrimitiveBuilder<Float32Type>),
+    Float64(PrimitiveBuilder<Float64Type>),
+    Str(StringBuilder),
+    /// The builder, precision and scale
+    Decimal128(Decimal128Builder, u8, i8),
+    /// The builder, precision and scale
+    Decimal256(Decimal256Builder, u8, i8),
+    Date(PrimitiveBuilder<Date32Type>),
+    Timestamp(PrimitiveBuilder<TimestampNanosecondType>),
+    Binary(BinaryBuilder),
+    List {
+        field: FieldRef,
+        offsets: Vec<i32>,
+        nulls: NullBufferBuilder,
+        items: Box<ValueBuilder>,
+    },
+    Struct {
+        fields: Fields,
+        nulls: NullBufferBuilder,
+        children: Vec<ValueBuilder>,
+    },
+    Map {
+        field: FieldRef,
+        offsets: Vec<i32>,
+        nulls: NullBufferBuilder,
+        keys: StringBuilder,
+        values: Box<ValueBuilder>,
+    },
+}
+
+impl ValueBuilder {
+    fn try_new(data_type: &DataType, capacity: usize) -> DataFusionResult<Self> {
+        let builder = match data_type {
+            DataType::Boolean => Self::Bool(BooleanBuilder::with_capacity(capacity)),
+            DataType::Int8 => Self::Int8(PrimitiveBuilder::with_capacity(capacity)),
+            DataType::Int16 => Self::Int16(PrimitiveBuilder::with_capacity(capacity)),
+            DataType::Int32 => Self::Int32(PrimitiveBuilder::with_capacity(capacity)),
+            DataType::Int64 => Self::Int64(PrimitiveBuilder::with_capacity(capacity)),
+            DataType::Float32 => Self::Float32(PrimitiveBuilder::with_capacity(capacity)),
+            DataType::Float64 => Self::Float64(PrimitiveBuilder::with_capacity(capacity)),
+            DataType::Utf8 => Self::Str(StringBuilder::with_capacity(capacity, 0)),
+            DataType::Decimal128(precision, scale) => Self::Decimal128(
+                Decimal128Builder::with_capacity(capacity).with_precision_and_scale(*precision, *scale)?,
+                *precision,
+                *scale,
+            ),
+            DataType::Decimal256(precision, scale) => Self::Decimal256(
+                Decimal256Builder::with_capacity(capacity).with_precision_and_scale(*precision, *scale)?,
+                *precision,
+                *scale,
+            ),
+            DataType::Date32 => Self::Date(PrimitiveBuilder::with_capacity(capacity)),
+            DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
+                Self::Timestamp(PrimitiveBuilder::with_capacity(capacity).with_timezone_opt(tz.clone()))
+            }
+            DataType::Binary => Self::Binary(BinaryBuilder::with_capacity(capacity, 0)),
+            DataType::List(field) => Self::List {
+                field: field.clone(),
+                offsets: vec![0],
+                nulls: NullBufferBuilder::new(capacity),
+                items: Box::new(Self::try_new(field.data_type(), capacity)?),
+            },
+            DataType::Struct(fields) => Self::Struct {
+                fields: fields.clone(),
+                nulls: NullBufferBuilder::new(capacity),
+                children: fields
+                    .iter()
+                    .map(|field| Self::try_new(field.data_type(), capacity))
+                    .collect::<DataFusionResult<_>>()?,
+            },
+            DataType::Map(field, _) => {
+                let DataType::Struct(entries) = field.data_type() else {
+                    return exec_err!("unexpected map entries type {:?}", field.data_type());
+                };
+                Self::Map {
+                    field: field.clone(),
+                    offsets: vec![0],
+                    nulls: NullBufferBuilder::new(capacity),
+                    keys: StringBuilder::with_capacity(capacity, 0),
+                    values: Box::new(Self::try_new(entries[1].data_type(), capacity)?),
+                }
+            }
+            other => return exec_err!("unsupported from_json type {other:?}"),
+        };
+        Ok(builder)
+    }
+
+    fn append_null(&mut self) {
+        match self {
+            Self::Bool(builder) => builder.append_null(),
+            Self::Int8(builder) => builder.append_null(),
+            Self::Int16(builder) => builder.append_null(),
+            Self::Int32(builder) => builder.append_null(),
+            Self::Int64(builder) => builder.append_null(),
+            Self::Float32(builder) => builder.append_null(),
+            Self::Float64(builder) => builder.append_null(),
+            Self::Str(builder) => builder.append_null(),
+            Self::Decimal128(builder, ..) => builder.append_null(),
+            Self::Decimal256(builder, ..) => builder.append_null(),
+            Self::Date(builder) => builder.append_null(),
+            Self::Timestamp(builder) => builder.append_null(),
+            Self::Binary(builder) => builder.append_null(),
+            Self::List { offsets, nulls, .. } | Self::Map { offsets, nulls, .. } => {
+                offsets.push(*offsets.last().unwrap_or(&0));
+                nulls.append_null();
+            }
+            Self::Struct { nulls, children, .. } => {
+                children.iter_mut().for_each(Self::append_null);
+                nulls.append_null();
+            }
+        }
+    }
+
+    /// Append the value starting at `peek`, exactly one value is always appended.
+    ///
+    /// An error means the JSON is invalid and `jiter` can't be used further, the value is null in that case.
+    fn append_value(&mut self, jiter: &mut Jiter, peek: Peek) -> Result<(), JiterError> {
+        match self {
+            Self::Bool(builder) => {
+                let value = match peek {
+                    Peek::True | Peek::False => jiter.known_bool(peek).map(Some),
+                    _ => jiter.known_skip(peek).map(|()| None),
+                };
+                builder.append_option(value.as_ref().ok().copied().flatten());
+                value.map(|_| ())
+            }
+            Self::Int8(builder) => append_int(builder, jiter, peek),
+            Self::Int16(builder) => append_int(builder, jiter, peek),
+            Self::Int32(builder) => append_int(builder, jiter, peek),
+            Self::Int64(builder) => append_int(builder, jiter, peek),
+            Self::Float32(builder) => {
+                #[allow(clippy::cast_possible_truncation)]
+                let value = read_float(jiter, peek).map(|value| value.map(|f| f as f32));
+                builder.append_option(value.as_ref().ok().copied().flatten());
+                value.map(|_| ())
+            }
+            Self::Float64(builder) => {
+                let value = read_float(jiter, peek);
+                builder.append_option(value.as_ref().ok().copied().flatten());
+                value.map(|_| ())
+            }
+            Self::Str(builder) => {
+                let value = read_str(jiter, peek);
+                builder.append_option(value.as_ref().ok().and_then(Option::as_ref));
+                value.map(|_| ())
+            }
+            Self::Decimal128(builder, precision, scale) => append_decimal(builder, jiter, peek, *precision, *scale),
+            Self::Decimal256(builder, precision, scale) => append_decimal(builder, jiter, peek, *precision, *scale),
+            Self::Date(builder) => {
+                let value = read_temporal(jiter, peek).map(|value| date_days(&value?, &TemporalOptions::default()));
+                append_primitive(builder, value)
+            }
+            Self::Timestamp(builder) => {
+                let value =
+                    read_temporal(jiter, peek).map(|value| timestamp_nanos(&value?, &TemporalOptions::default()));
+                append_primitive(builder, value)
+            }
+            Self::Binary(builder) => {
+                let value = read_binary(jiter, peek);
+                builder.append_option(value.as_ref().ok().and_then(Option::as_ref));
+                value.map(|_| ())
+            }
+            Self::List {
+                offsets, nulls, items, ..
+            } => {
+                let result = if peek == Peek::Array {
+                    append_items(items, jiter)
+                } else {
+                    jiter.known_skip(peek).map(|()| Err(()))
+                };
+                offsets.push(i32::try_from(items.len()).expect("list offsets out of i32 range"));
+                nulls.append(matches!(result, Ok(Ok(()))));
+                result.map(|_| ())
+            }
+            Self::Struct {
+                fields,
+                nulls,
+                children,
+            } => {
+                let result = if peek == Peek::Object {
+                    append_fields(fields, children, jiter)
+                } else {
+                    children.iter_mut().for_each(Self::append_null);
+                    jiter.known_skip(peek).map(|()| Err(()))
+                };
+                nulls.append(matches!(result, Ok(Ok(()))));
+                result.map(|_| ())
+            }
+            Self::Map {
+                offsets,
+                nulls,
+                keys,
+                values,
+                ..
+            } => {
+                let result = if peek == Peek::Object {
+                    append_entries(keys, values, jiter)
+                } else {
+                    jiter.known_skip(peek).map(|()| Err(()))
+                };
+                offsets.push(i32::try_from(values.len()).expect("map offsets out of i32 range"));
+                nulls.append(matches!(result, Ok(Ok(()))));
+                result.map(|_| ())
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Bool(builder) => builder.len(),
+            Self::Int8(builder) => builder.len(),
+            Self::Int16(builder) => builder.len(),
+            Self::Int32(builder) => builder.len(),
+            Self::Int64(builder) => builder.len(),
+            Self::Float32(builder) => builder.len(),
+            Self::Float64(builder) => builder.len(),
+            Self::Str(builder) => builder.len(),
+            Self::Decimal128(builder, ..) => builder.len(),
+            Self::Decimal256(builder, ..) => builder.len(),
+            Self::Date(builder) => builder.len(),
+            Self::Timestamp(builder) => builder.len(),
+            Self::Binary(builder) => builder.len(),
+            Self::List { nulls, .. } | Self::Struct { nulls, .. } | Self::Map { nulls, .. } => nulls.len(),
+        }
+    }
+
+    fn finish(self) -> DataFusionResult<ArrayRef> {
+        let array: ArrayRef = match self {
+            Self::Bool(mut builder) => Arc::new(builder.finish()),
+            Self::Int8(mut builder) => Arc::new(builder.finish()),
+            Self::Int16(mut builder) => Arc::new(builder.finish()),
+            Self::Int32(mut builder) => Arc::new(builder.finish()),
+            Self::Int64(mut builder) => Arc::new(builder.finish()),
+            Self::Float32(mut builder) => Arc::new(builder.finish()),
+            Self::Float64(mut builder) => Arc::new(builder.finish()),
+            Self::Str(mut builder) => Arc::new(builder.finish()),
+            Self::Decimal128(mut builder, ..) => Arc::new(builder.finish()),
+            Self::Decimal256(mut builder, ..) => Arc::new(builder.finish()),
+            Self::Date(mut builder) => Arc::new(builder.finish()),
+            Self::Timestamp(mut builder) => Arc::new(builder.finish()),
+            Self::Binary(mut builder) => Arc::new(builder.finish()),
+            Self::List {
+                field,
+                offsets,
+                mut nulls,
+                items,
+            } => Arc::new(ListArray::try_new(
+                field,
+                OffsetBuffer::new(ScalarBuffer::from(offsets)),
+                items.finish()?,
+                nulls.finish(),
+            )?),
+            Self::Struct {
+                fields,
+                mut nulls,
+                children,
+            } => {
+                let children = children
+                    .into_iter()
+                    .map(Self::finish)
+                    .collect::<DataFusionResult<Vec<_>>>()?;
+                Arc::new(StructArray::try_new(fields, children, nulls.finish())?)
+            }
+            Self::Map {
+                field,
+                offsets,
+                mut nulls,
+                mut keys,
+                values,
+            } => {
+                let DataType::Struct(entry_fields) = field.data_type() else {
+                    return exec_err!("unexpected map entries type {:?}", field.data_type());
+                };
+                let entries = StructArray::try_new(
+                    entry_fields.clone(),
+                    vec![Arc::new(keys.finish()), values.finish()?],
+                    None,
+                )?;
+                Arc::new(MapArray::try_new(
+                    field,
+                    OffsetBuffer::new(ScalarBuffer::from(offsets)),
+                    entries,
+                    nulls.finish(),
+                    false,
+                )?)
+            }
+        };
+        Ok(array)
+    }
+}
+
+/// The result of appending a nested value, `Ok(Err(()))` means the JSON value had the wrong type.
+type NestedResult = Result<Result<(), ()>, JiterError>;
+
+fn append_items(items: &mut ValueBuilder, jiter: &mut Jiter) -> NestedResult {
+    let mut next_item = jiter.known_array()?;
+    while let Some(peek) = next_item {
+        items.append_value(jiter, peek)?;
+        next_item = jiter.array_step()?;
+    }
+    Ok(Ok(()))
+}
+
+fn append_fields(fields: &Fields, children: &mut [ValueBuilder], jiter: &mut Jiter) -> NestedResult {
+    let start_len: Vec<usize> = children.iter().map(ValueBuilder::len).collect();
+    let result = append_object_fields(fields, children, jiter, &start_len);
+    // fields which were missing (or come after invalid JSON) are null
+    for (child, start_len) in children.iter_mut().zip(start_len) {
+        if child.len() == start_len {
+            child.append_null();
+        }
+    }
+    result.map(Ok)
+}
+
+fn append_object_fields(
+    fields: &Fields,
+    children: &mut [ValueBuilder],
+    jiter: &mut Jiter,
+    start_len: &[usize],
+) -> Result<(), JiterError> {
+    let mut next_key = jiter.known_object()?;
+    while let Some(key) = next_key {
+        let index = fields.iter().position(|field| field.name() == key);
+        let peek = jiter.peek()?;
+        match index {
+            // the first of any duplicate keys is used
+            Some(index) if children[index].len() == start_len[index] => children[index].append_value(jiter, peek)?,
+            _ => jiter.known_skip(peek)?,
+        }
+        next_key = jiter.next_key()?;
+    }
+    Ok(())
+}
+
+fn append_entries(keys: &mut StringBuilder, values: &mut ValueBuilder, jiter: &mut Jiter) -> NestedResult {
+    let mut next_key = jiter.known_object()?;
+    while let Some(key) = next_key {
+        keys.append_value(key);
+        let peek = jiter.peek();
+        match peek {
+            Ok(peek) => values.append_value(jiter, peek)?,
+            Err(e) => {
+                // keep keys and values the same length
+                values.append_null();
+                return Err(e);
+            }
+        }
+        next_key = jiter.next_key()?;
+    }
+    Ok(Ok(()))
+}
+
+fn append_int<T: ArrowPrimitiveType>(
+    builder: &mut PrimitiveBuilder<T>,
+    jiter: &mut Jiter,
+    peek: Peek,
+) -> Result<(), JiterError>
+where
+    T::Native: TryFrom<i64>,
+{
+    append_primitive(
+        builder,
+        if peek.is_num() {
+            jiter.known_number(peek).map(|number| match number {
+                NumberAny::Int(NumberInt::Int(value)) => T::Native::try_from(value).ok(),
+                NumberAny::Int(NumberInt::BigInt(_)) | NumberAny::Float(_) => None,
+            })
+        } else {
+            jiter.known_skip(peek).map(|()| None)
+        },
+    )
+}
+
+fn append_decimal<T: DecimalType>(
+    builder: &mut PrimitiveBuilder<T>,
+    jiter: &mut Jiter,
+    peek: Peek,
+    precision: u8,
+    scale: i8,
+) -> Result<(), JiterError> {
+    let value = read_decimal_text(jiter, peek).map(|text| parse_decimal_rounded::<T>(text?, precision, scale).ok());
+    append_primitive(builder, value)
+}
+
+fn append_primitive<T: ArrowPrimitiveType>(
+    builder: &mut PrimitiveBuilder<T>,
+    value: Result<Option<T::Native>, JiterError>,
+) -> Result<(), JiterError> {
+    builder.append_option(value.as_ref().ok().copied().flatten());
+    value.map(|_| ())
+}
+
+fn read_float(jiter: &mut Jiter, peek: Peek) -> Result<Option<f64>, JiterError> {
+    if peek.is_num() {
+        jiter.known_float(peek).map(Some)
+    } else {
+        jiter.known_skip(peek).map(|()| None)
+    }
+}
+
+fn read_str(jiter: &mut Jiter, peek: Peek) -> Result<Option<String>, JiterError> {
+    match peek {
+        Peek::String => jiter.known_str().map(|s| Some(s.to_owned())),
+        Peek::Null => jiter.known_null().map(|()| None),
+        _ => {
+            let start = jiter.current_index();
+            jiter.known_skip(peek)?;
+            Ok(Some(
+                String::from_utf8_lossy(jiter.slice_to_current(start)).into_owned(),
+            ))
+        }
+    }
+}
+
+/// Binary values are strings of hex digits, as written by `to_json`.
+fn read_binary(jiter: &mut Jiter, peek: Peek) -> Result<Option<Vec<u8>>, JiterError> {
+    if peek == Peek::String {
+        jiter.known_str().map(decode_hex)
+    } else {
+        jiter.known_skip(peek).map(|()| None)
+    }
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{new_empty_array, Array, ArrayRef, AsArray, ListArray, StringArray, StructArray};
+use datafusion::arrow::buffer::{Buffer, OffsetBuffer};
+use datafusion::arrow::compute::{concat, lexsort_to_indices, SortColumn, SortOptions};
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion::common::{exec_datafusion_err, not_impl_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::{format_state_name, AggregateOrderSensitivity};
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, ColumnarValue, Signature, Volatility};
+
+use crate::common_encode::{check_encodable, json_text_field, ColumnEncoder};
+use crate::common_macros::make_udaf_function;
+
+make_udaf_function!(
+    JsonAgg,
+    json_agg,
+    value,
+    r"Aggregate values into a JSON array, in the order of the aggregate's `ORDER BY` if it has one"
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonAgg {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonAgg {
+    fn default() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+            aliases: ["json_agg".to_string()],
+        }
+    }
+}
+
+impl AggregateUDFImpl for JsonAgg {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        json_agg_return_type(arg_types, self.name())
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> DataFusionResult<FieldRef> {
+        let arg_types: Vec<DataType> = arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        self.return_type(&arg_types)?;
+        Ok(json_text_field(self.name()))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> DataFusionResult<Box<dyn Accumulator>> {
+        Ok(Box::new(JsonAggAccumulator::try_new(&acc_args, self.name(), false)?))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> DataFusionResult<Vec<FieldRef>> {
+        Ok(json_agg_state_fields(&args))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn order_sensitivity(&self) -> AggregateOrderSensitivity {
+        AggregateOrderSensitivity::HardRequirement
+    }
+}
+
+/// The aggregated values can be of any type which can be encoded as JSON.
+pub(crate) fn json_agg_return_type(arg_types: &[DataType], fn_name: &str) -> DataFusionResult<DataType> {
+    arg_types
+        .iter()
+        .try_for_each(|arg_type| check_encodable(arg_type, fn_name))?;
+    Ok(DataType::Utf8)
+}
+
+/// The state is a list of the encoded elements, and with an `ORDER BY`, a list of the ordering values of each element.
+pub(crate) fn json_agg_state_fields(args: &StateFieldsArgs) -> Vec<FieldRef> {
+    let mut fields = vec![Arc::new(Field::new_list(
+        format_state_name(args.name, "json"),
+        Field::new_list_field(DataType::Utf8, true),
+        true,
+    ))];
+    if !args.ordering_fields.is_empty() {
+        fields.push(Arc::new(Field::new_list(
+            format_state_name(args.name, "ordering"),
+            Field::new_list_field(DataType::Struct(Fields::from(args.ordering_fields.to_vec())), true),
+            true,
+        )));
+    }
+    fields
+}
+
+/// Accumulates the JSON of each element, a value or an object entry, in one buffer rather than a `ScalarValue` each.
+///
+/// With an `ORDER BY`, the arrays of ordering values of each batch are kept so partial aggregates can be merged, and
+/// the elements are sorted once when the aggregate is evaluated.
+#[derive(Debug)]
+pub(crate) struct JsonAggAccumulator {
+    /// Whether elements are `"key":value` entries of an object rather than values of an array
+    object: bool,
+    /// The fields of the aggregated arguments, which decide whether strings are embedded as raw JSON
+    arg_fields: Vec<FieldRef>,
+    /// The encoded elements, concatenated
+    json: Vec<u8>,
+    /// The end of each element in `json`
+    ends: Vec<usize>,
+    /// The ordering values of the elements as a list of arrays for each ordering column, empty without an `ORDER BY`
+    orderings: Vec<Vec<ArrayRef>>,
+    ordering_fields: Fields,
+    sort_options: Vec<SortOptions>,
+}
+
+impl JsonAggAccumulator {
+    pub(crate) fn try_new(acc_args: &AccumulatorArgs, fn_name: &str, object: bool) -> DataFusionResult<Self> {
+        if acc_args.is_distinct {
+            return not_impl_err!("DISTINCT isn't supported by '{fn_name}'");
+        }
+        // named like the ordering fields DataFusion passes to `state_fields`
+        let ordering_fields = acc_args
+            .order_bys
+            .iter()
+            .map(|sort_expr| {
+                let data_type = sort_expr.expr.data_type(acc_args.schema)?;
+                Ok(Field::new(sort_expr.expr.to_string(), data_type, true))
+            })
+            .collect::<DataFusionResult<Vec<_>>>()?;
+        Ok(Self {
+            object,
+            arg_fields: acc_args.expr_fields.to_vec(),
+            json: Vec::new(),
+            ends: Vec::new(),
+            orderings: vec![Vec::new(); ordering_fields.len()],
+            ordering_fields: ordering_fields.into(),
+            sort_options: acc_args.order_bys.iter().map(|sort_expr| sort_expr.options).collect(),
+        })
+    }
+
+    fn push_element(&mut self, encoders: &[ColumnEncoder], row: usize) -> DataFusionResult<()> {
+        if let [key, value] = encoders {
+            key.encode_key(row, &mut self.json)?;
+            self.json.push(b':');
+            value.encode(row, &mut self.json)?;
+        } else {
+            encoders[0].encode(row, &mut self.json)?;
+        }
+        self.ends.push(self.json.len());
+        Ok(())
+    }
+
+    fn element(&self, index: usize) -> &[u8] {
+        let start = if index == 0 { 0 } else { self.ends[index - 1] };
+        &self.json[start..self.ends[index]]
+    }
+
+    fn elements_array(&self) -> DataFusionResult<StringArray> {
+        let offsets = std::iter::once(0)
+            .chain(self.ends.iter().copied())
+            .map(i32::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| exec_datafusion_err!("The aggregated JSON is too large"))?;
+        Ok(StringArray::try_new(
+            OffsetBuffer::new(offsets.into()),
+            Buffer::from_slice_ref(&self.json),
+            None,
+        )?)
+    }
+
+    /// The ordering values of all the elements, one array for each ordering column.
+    fn ordering_columns(&self) -> DataFusionResult<Vec<ArrayRef>> {
+        self.orderings
+            .iter()
+            .zip(&self.ordering_fields)
+            .map(|(arrays, field)| {
+                if arrays.is_empty() {
+                    Ok(new_empty_array(field.data_type()))
+                } else {
+                    let arrays: Vec<&dyn Array> = arrays.iter().map(AsRef::as_ref).collect();
+                    Ok(concat(&arrays)?)
+                }
+            })
+            .collect()
+    }
+
+    /// The order to output the elements in, sorted by the ordering values if there's an `ORDER BY`.
+    fn element_order(&self) -> DataFusionResult<Vec<usize>> {
+        if self.sort_options.is_empty() {
+            return Ok((0..self.ends.len()).collect());
+        }
+        let sort_columns: Vec<SortColumn> = self
+            .ordering_columns()?
+            .into_iter()
+            .zip(&self.sort_options)
+            .map(|(values, options)| SortColumn {
+                values,
+                options: Some(*options),
+            })
+            .collect();
+        let indices = lexsort_to_indices(&sort_columns, None)?;
+        Ok(indices.values().iter().map(|&index| index as usize).collect())
+    }
+}
+
+impl Accumulator for JsonAggAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> DataFusionResult<()> {
+        // the ordering values follow the aggregated arguments
+        let (args, orderings) = values.split_at(self.arg_fields.len());
+        let encoders = args
+            .iter()
+            .zip(&self.arg_fields)
+            .map(|(array, field)| ColumnEncoder::try_new(&ColumnarValue::Array(Arc::clone(array)), field))
+            .collect::<DataFusionResult<Vec<_>>>()?;
+        let rows = args.first().map_or(0, Array::len);
+        for row in 0..rows {
+            self.push_element(&encoders, row)?;
+        }
+        for (arrays, array) in self.orderings.iter_mut().zip(orderings) {
+            arrays.push(Arc::clone(array));
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> DataFusionResult<ScalarValue> {
+        if self.ends.is_empty() {
+            return Ok(ScalarValue::Utf8(None));
+        }
+        let order = self.element_order()?;
+        let (open, close) = if self.object { (b'{', b'}') } else { (b'[', b']') };
+        let mut json = Vec::with_capacity(self.json.len() + self.ends.len() + 1);
+        json.push(open);
+        for (i, &index) in order.iter().enumerate() {
+            if i > 0 {
+                json.push(b',');
+            }
+            json.extend_from_slice(self.element(index));
+        }
+        json.push(close);
+        let json = String::from_utf8(json).map_err(|e| exec_datafusion_err!("Failed to encode JSON: {e}"))?;
+        Ok(ScalarValue::Utf8(Some(json)))
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+            + self.json.capacity()
+            + self.ends.capacity() * size_of::<usize>()
+            + self
+                .orderings
+                .iter()
+                .map(|arrays| {
+                    arrays.capacity() * size_of::<ArrayRef>()
+                        + arrays.iter().map(Array::get_array_memory_size).sum::<usize>()
+                })
+                .sum::<usize>()
+    }
+
+    fn state(&mut self) -> DataFusionResult<Vec<ScalarValue>> {
+        let mut state = vec![single_row_list(Arc::new(self.elements_array()?))];
+        if !self.ordering_fields.is_empty() {
+            let orderings = StructArray::try_new(self.ordering_fields.clone(), self.ordering_columns()?, None)?;
+            state.push(single_row_list(Arc::new(orderings)));
+        }
+        Ok(state)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> DataFusionResult<()> {
+        let elements = states[0].as_list::<i32>();
+        let orderings = states.get(1).map(AsArray::as_list::<i32>);
+        for row in 0..elements.len() {
+            if elements.is_null(row) {
+                continue;
+            }
+            let row_elements = elements.value(row);
+            for element in row_elements.as_string::<i32>() {
+                // elements are never null, an element which is SQL null is encoded as `null`
+                self.json.extend_from_slice(element.unwrap_or("null").as_bytes());
+                self.ends.push(self.json.len());
+            }
+            if let Some(orderings) = orderings {
+                let row_orderings = orderings.value(row);
+                for (arrays, column) in self.orderings.iter_mut().zip(row_orderings.as_struct().columns()) {
+                    arrays.push(Arc::clone(column));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn single_row_list(values: ArrayRef) -> ScalarValue {
+    let field = Arc::new(Field::new_list_field(values.data_type().clone(), true));
+    let offsets = OffsetBuffer::from_lengths([values.len()]);
+    ScalarValue::List(Arc::new(ListArray::new(field, offsets, values, None)))
+}
@@ -0,0 +1,80 @@
+use datafusion::arrow::datatypes::{DataType, FieldRef};
+use datafusion::common::Result as DataFusionResult;
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+
+use crate::common_encode::{check_encodable, encode_rows, json_text_field, ColumnEncoder};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonBuildArray,
+    json_build_array,
+    value,
+    r"Build a JSON array from any number of values"
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonBuildArray {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonBuildArray {
+    fn default() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::VariadicAny, TypeSignature::Nullary],
+                Volatility::Immutable,
+            ),
+            aliases: ["json_build_array".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonBuildArray {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        arg_types
+            .iter()
+            .try_for_each(|arg_type| check_encodable(arg_type, self.name()))?;
+        Ok(DataType::Utf8)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        self.return_type(&arg_types)?;
+        Ok(json_text_field(self.name()))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let encoders = args
+            .args
+            .iter()
+            .zip(&args.arg_fields)
+            .map(|(arg, field)| ColumnEncoder::try_new(arg, field))
+            .collect::<DataFusionResult<Vec<_>>>()?;
+        encode_rows(&args.args, args.number_rows, |row, out| {
+            out.push(b'[');
+            for (i, encoder) in encoders.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                encoder.encode(row, out)?;
+            }
+            out.push(b']');
+            Ok(true)
+        })
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
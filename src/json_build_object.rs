@@ -0,0 +1,88 @@
+use datafusion::arrow::datatypes::{DataType, FieldRef};
+use datafusion::common::{plan_err, Result as DataFusionResult};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+
+use crate::common_encode::{check_encodable, encode_rows, json_text_field, ColumnEncoder};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonBuildObject,
+    json_build_object,
+    key value,
+    r"Build a JSON object from alternating keys and values"
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonBuildObject {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonBuildObject {
+    fn default() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::VariadicAny, TypeSignature::Nullary],
+                Volatility::Immutable,
+            ),
+            aliases: ["json_build_object".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonBuildObject {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        if !arg_types.len().is_multiple_of(2) {
+            return plan_err!(
+                "The '{}' function requires an even number of arguments, alternating keys and values.",
+                self.name()
+            );
+        }
+        arg_types
+            .iter()
+            .try_for_each(|arg_type| check_encodable(arg_type, self.name()))?;
+        Ok(DataType::Utf8)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        self.return_type(&arg_types)?;
+        Ok(json_text_field(self.name()))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let encoders = args
+            .args
+            .iter()
+            .zip(&args.arg_fields)
+            .map(|(arg, field)| ColumnEncoder::try_new(arg, field))
+            .collect::<DataFusionResult<Vec<_>>>()?;
+        encode_rows(&args.args, args.number_rows, |row, out| {
+            out.push(b'{');
+            for (i, entry) in encoders.chunks_exact(2).enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                entry[0].encode_key(row, out)?;
+                out.push(b':');
+                entry[1].encode(row, out)?;
+            }
+            out.push(b'}');
+            Ok(true)
+        })
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
@@ -0,0 +1,71 @@
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::{exec_err, Result as DataFusionResult};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+
+use crate::common_macros::make_udf_function;
+use crate::json_contains_value::{containment_return_type, invoke_contains};
+
+make_udf_function!(
+    JsonContainedIn,
+    json_contained_in,
+    json_data container,
+    r#"Is the JSON value contained in the "container" JSON value? Used for the `<@` operator"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonContainedIn {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonContainedIn {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_contained_in".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonContainedIn {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        containment_return_type(arg_types, self.name())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let [json_data, container] = args.args.as_slice() else {
+            return exec_err!("The '{}' function requires two arguments.", self.name());
+        };
+        invoke_contains(container, json_data)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a pattern)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
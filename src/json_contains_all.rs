@@ -0,0 +1,68 @@
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::Result;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+
+use crate::common_macros::make_udf_function;
+use crate::json_contains_any::{contains_keys_return_type, invoke_contains_keys};
+
+make_udf_function!(
+    JsonContainsAll,
+    json_contains_all,
+    json_data keys,
+    r#"Does the JSON object have all of the keys? The keys are either a list after the "path", or several strings"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonContainsAll {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonContainsAll {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_contains_all".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonContainsAll {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        contains_keys_return_type(arg_types, self.name())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        invoke_contains_keys(&args.args, self.name(), true)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path and keys)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use datafusion::arrow::array::{Array, GenericListArray, OffsetSizeTrait};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::arrow::array::BooleanArray;
+use datafusion::common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use jiter::Peek;
+
+use crate::common::{invoke, jiter_json_find, json_values, return_type_check, GetError, JsonPath};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonContainsAny,
+    json_contains_any,
+    json_data keys,
+    r#"Does the JSON object have any of the keys? The keys are either a list after the "path", or several strings"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonContainsAny {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonContainsAny {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_contains_any".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonContainsAny {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        contains_keys_return_type(arg_types, self.name())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        invoke_contains_keys(&args.args, self.name(), false)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path and keys)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+pub(crate) fn contains_keys_return_type(arg_types: &[DataType], fn_name: &str) -> Result<DataType> {
+    if arg_types.len() < 2 {
+        plan_err!("The '{fn_name}' function requires two or more arguments.")
+    } else {
+        return_type_check(arg_types, fn_name, DataType::Boolean).map(|_| DataType::Boolean)
+    }
+}
+
+/// Check the object at the path for `keys`, which are either a list of strings as the last argument, after the path,
+/// or every argument after the JSON.
+pub(crate) fn invoke_contains_keys(args: &[ColumnarValue], fn_name: &str, all: bool) -> Result<ColumnarValue> {
+    let (path_end, mut keys) = match args.last() {
+        Some(ColumnarValue::Scalar(ScalarValue::List(list))) => (args.len() - 1, list_keys(list)?),
+        Some(ColumnarValue::Scalar(ScalarValue::LargeList(list))) => (args.len() - 1, list_keys(list)?),
+        _ if args[1..].iter().any(|arg| matches!(arg, ColumnarValue::Array(_))) => {
+            return invoke_contains_keys_by_row(args, fn_name, all);
+        }
+        _ => {
+            let keys = args[1..]
+                .iter()
+                .map(|arg| {
+                    let ColumnarValue::Scalar(
+                        ScalarValue::Utf8(key) | ScalarValue::Utf8View(key) | ScalarValue::LargeUtf8(key),
+                    ) = arg
+                    else {
+                        return exec_err!("The keys of '{fn_name}' must be strings, or a list of strings.");
+                    };
+                    Ok(key.clone())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            (1, keys.into_iter().flatten().collect())
+        }
+    };
+    keys.sort_unstable();
+    keys.dedup();
+    invoke::<BooleanArray>(&args[..path_end], |json, path| {
+        jiter_json_contains_keys(json, path, &keys, all)
+    })
+}
+
+/// Check each row on its own, where the keys (or the path before a list of keys) come from a column.
+fn invoke_contains_keys_by_row(args: &[ColumnarValue], fn_name: &str, all: bool) -> Result<ColumnarValue> {
+    let num_rows = args
+        .iter()
+        .find_map(|arg| match arg {
+            ColumnarValue::Array(array) => Some(array.len()),
+            ColumnarValue::Scalar(_) => None,
+        })
+        .unwrap_or(1);
+    let values = (0..num_rows)
+        .map(|row| {
+            let row_args = args
+                .iter()
+                .map(|arg| match arg {
+                    ColumnarValue::Array(array) => ScalarValue::try_from_array(array, row).map(ColumnarValue::Scalar),
+                    ColumnarValue::Scalar(scalar) => Ok(ColumnarValue::Scalar(scalar.clone())),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            match invoke_contains_keys(&row_args, fn_name, all)? {
+                ColumnarValue::Scalar(ScalarValue::Boolean(value)) => Ok(value),
+                other => internal_err!("unexpected '{fn_name}' result {other:?}"),
+            }
+        })
+        .collect::<Result<BooleanArray>>()?;
+    Ok(ColumnarValue::Array(std::sync::Arc::new(values)))
+}
+
+fn list_keys<O: OffsetSizeTrait>(list: &GenericListArray<O>) -> Result<Vec<String>> {
+    if list.is_null(0) {
+        return Ok(Vec::new());
+    }
+    Ok(json_values(&list.value(0))?
+        .into_iter()
+        .flatten()
+        .map(str::to_string)
+        .collect())
+}
+
+/// Walk the object once, `keys` must be sorted and unique.
+#[allow(clippy::unnecessary_wraps)]
+fn jiter_json_contains_keys(
+    json_data: Option<&str>,
+    path: &[JsonPath],
+    keys: &[String],
+    all: bool,
+) -> Result<bool, GetError> {
+    let Some((mut jiter, Peek::Object)) = jiter_json_find(json_data, path) else {
+        return Ok(false);
+    };
+    let mut found = vec![false; keys.len()];
+    let mut found_count = 0;
+    let mut opt_key = jiter.known_object().ok().flatten();
+    while let Some(key) = opt_key {
+        if let Ok(index) = keys.binary_search_by(|k| k.as_str().cmp(key)) {
+            if !all {
+                return Ok(true);
+            }
+            if !found[index] {
+                found[index] = true;
+                found_count += 1;
+            }
+        }
+        if jiter.next_skip().is_err() {
+            break;
+        }
+        opt_key = jiter.next_key().ok().flatten();
+    }
+    Ok(all && found_count == keys.len())
+}
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::BooleanArray;
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::{exec_err, plan_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use jiter::JsonValue;
+
+use crate::common::{json_values, return_type_check, unpack_dictionary};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonContainsValue,
+    json_contains_value,
+    json_data pattern,
+    r#"Does the JSON value contain the "pattern" JSON value? Used for the `@>` operator"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonContainsValue {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonContainsValue {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_contains_value".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonContainsValue {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        containment_return_type(arg_types, self.name())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let [doc, pattern] = args.args.as_slice() else {
+            return exec_err!("The '{}' function requires two arguments.", self.name());
+        };
+        invoke_contains(doc, pattern)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a pattern)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// Both arguments of the containment functions are JSON.
+pub(crate) fn containment_return_type(arg_types: &[DataType], fn_name: &str) -> DataFusionResult<DataType> {
+    let [doc, pattern] = arg_types else {
+        return plan_err!("The '{fn_name}' function requires two arguments, a JSON value and a JSON pattern.");
+    };
+    return_type_check(std::slice::from_ref(doc), fn_name, DataType::Boolean)?;
+    return_type_check(std::slice::from_ref(pattern), fn_name, DataType::Boolean)?;
+    Ok(DataType::Boolean)
+}
+
+/// Does each `doc` contain `pattern`, with the semantics of the Postgres `jsonb @>` operator.
+///
+/// A literal on either side is parsed once for the whole batch rather than for every row.
+pub(crate) fn invoke_contains(doc: &ColumnarValue, pattern: &ColumnarValue) -> DataFusionResult<ColumnarValue> {
+    match (doc, pattern) {
+        (ColumnarValue::Scalar(doc), ColumnarValue::Scalar(pattern)) => {
+            let (doc, pattern) = (parse_scalar(doc)?, parse_scalar(pattern)?);
+            Ok(ColumnarValue::Scalar(ScalarValue::Boolean(contains(
+                doc.as_ref(),
+                pattern.as_ref(),
+            ))))
+        }
+        (ColumnarValue::Array(doc), ColumnarValue::Scalar(pattern)) => {
+            let pattern = parse_scalar(pattern)?;
+            let doc = unpack_dictionary(doc)?;
+            let result: BooleanArray = json_values(&doc)?
+                .into_iter()
+                .map(|doc| contains(parse(doc).as_ref(), pattern.as_ref()))
+                .collect();
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+        (ColumnarValue::Scalar(doc), ColumnarValue::Array(pattern)) => {
+            let doc = parse_scalar(doc)?;
+            let pattern = unpack_dictionary(pattern)?;
+            let result: BooleanArray = json_values(&pattern)?
+                .into_iter()
+                .map(|pattern| contains(doc.as_ref(), parse(pattern).as_ref()))
+                .collect();
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+        (ColumnarValue::Array(doc), ColumnarValue::Array(pattern)) => {
+            let (doc, pattern) = (unpack_dictionary(doc)?, unpack_dictionary(pattern)?);
+            let result: BooleanArray = json_values(&doc)?
+                .into_iter()
+                .zip(json_values(&pattern)?)
+                .map(|(doc, pattern)| contains(parse(doc).as_ref(), parse(pattern).as_ref()))
+                .collect();
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+}
+
+fn parse(json: Option<&str>) -> Option<JsonValue<'_>> {
+    JsonValue::parse(json?.as_bytes(), false).ok()
+}
+
+fn parse_scalar(scalar: &ScalarValue) -> DataFusionResult<Option<JsonValue<'static>>> {
+    let array = unpack_dictionary(&scalar.to_array()?)?;
+    Ok(json_values(&array)?
+        .first()
+        .and_then(|json| parse(*json))
+        .map(JsonValue::into_static))
+}
+
+/// Null if either side is null or invalid JSON.
+fn contains(doc: Option<&JsonValue>, pattern: Option<&JsonValue>) -> Option<bool> {
+    Some(value_contains(doc?, pattern?, true))
+}
+
+/// Objects contain objects whose entries they contain, and arrays contain arrays whose elements they each
+/// contain. As in Postgres, a top level array also contains any scalar which is one of its elements.
+fn value_contains(doc: &JsonValue, pattern: &JsonValue, top_level: bool) -> bool {
+    match (doc, pattern) {
+        (JsonValue::Object(doc), JsonValue::Object(pattern)) => pattern.iter().all(|(key, pattern_value)| {
+            // as in Postgres, the last of duplicate keys wins
+            doc.iter()
+                .rev()
+                .find(|(doc_key, _)| doc_key == key)
+                .is_some_and(|(_, doc_value)| value_contains(doc_value, pattern_value, false))
+        }),
+        (JsonValue::Array(doc), JsonValue::Array(pattern)) => pattern.iter().all(|pattern_element| {
+            doc.iter()
+                .any(|doc_element| value_contains(doc_element, pattern_element, false))
+        }),
+        (JsonValue::Array(doc), pattern) if top_level => doc.iter().any(|doc_element| scalar_eq(doc_element, pattern)),
+        (doc, pattern) => scalar_eq(doc, pattern),
+    }
+}
+
+/// Numbers are compared by value, so `1` and `1.0` are equal.
+#[allow(clippy::float_cmp, clippy::cast_precision_loss)]
+fn scalar_eq(doc: &JsonValue, pattern: &JsonValue) -> bool {
+    match (doc, pattern) {
+        (JsonValue::Null, JsonValue::Null) => true,
+        (JsonValue::Bool(doc), JsonValue::Bool(pattern)) => doc == pattern,
+        (JsonValue::Str(doc), JsonValue::Str(pattern)) => doc == pattern,
+        (JsonValue::Int(doc), JsonValue::Int(pattern)) => doc == pattern,
+        (JsonValue::BigInt(doc), JsonValue::BigInt(pattern)) => doc == pattern,
+        (JsonValue::Float(doc), JsonValue::Float(pattern)) => doc == pattern,
+        (JsonValue::Int(int), JsonValue::Float(float)) | (JsonValue::Float(float), JsonValue::Int(int)) => {
+            *int as f64 == *float
+        }
+        _ => false,
+    }
+}
@@ -106,7 +106,7 @@ impl InvokeResult for JsonUnion {
     }
 }
 
-fn jiter_json_get_union(opt_json: Option<&str>, path: &[JsonPath]) -> Result<JsonUnionField, GetError> {
+pub(crate) fn jiter_json_get_union(opt_json: Option<&str>, path: &[JsonPath]) -> Result<JsonUnionField, GetError> {
     if let Some((mut jiter, peek)) = jiter_json_find(opt_json, path) {
         build_union(&mut jiter, peek)
     } else {
@@ -114,7 +114,7 @@ fn jiter_json_get_union(opt_json: Option<&str>, path: &[JsonPath]) -> Result<Jso
     }
 }
 
-fn build_union(jiter: &mut Jiter, peek: Peek) -> Result<JsonUnionField, GetError> {
+pub(crate) fn build_union(jiter: &mut Jiter, peek: Peek) -> Result<JsonUnionField, GetError> {
     match peek {
         Peek::Null => {
             jiter.known_null()?;
@@ -144,7 +144,7 @@ fn build_union(jiter: &mut Jiter, peek: Peek) -> Result<JsonUnionField, GetError
         }
         _ => match jiter.known_number(peek)? {
             NumberAny::Int(NumberInt::Int(value)) => Ok(JsonUnionField::Int(value)),
-            NumberAny::Int(NumberInt::BigInt(_)) => todo!("BigInt not supported yet"),
+            NumberAny::Int(NumberInt::BigInt(value)) => Ok(JsonUnionField::BigInt(value.to_string())),
             NumberAny::Float(value) => Ok(JsonUnionField::Float(value)),
         },
     }
@@ -1,10 +1,13 @@
+use std::marker::PhantomData;
 use std::sync::Arc;
 
-use datafusion::arrow::array::{ArrayRef, ListBuilder, StringBuilder};
+use datafusion::arrow::array::{
+    ArrayBuilder, ArrayRef, BooleanBuilder, Float64Builder, Int64Builder, ListBuilder, StringBuilder,
+};
 use datafusion::arrow::datatypes::{DataType, Field};
 use datafusion::common::{Result as DataFusionResult, ScalarValue};
 use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
-use jiter::Peek;
+use jiter::{Jiter, JiterError, NumberAny, NumberInt, Peek};
 
 use crate::common::{get_err, invoke, jiter_json_find, return_type_check, GetError, InvokeResult, JsonPath};
 use crate::common_macros::make_udf_function;
@@ -142,3 +145,212 @@ fn jiter_json_get_array(opt_json: Option<&str>, path: &[JsonPath]) -> Result<Vec
         get_err!()
     }
 }
+
+/// An element type of the typed `json_get_*_array` functions.
+pub(crate) trait ListElement: Sized {
+    type Builder: ArrayBuilder + Default + Extend<Option<Self>>;
+
+    const DATA_TYPE: DataType;
+
+    /// Read the element starting at `peek`, elements of the wrong type are `None`.
+    fn read(jiter: &mut Jiter, peek: Peek) -> Result<Option<Self>, JiterError>;
+}
+
+/// The return type of a typed array function, a list of `T::DATA_TYPE`.
+pub(crate) fn typed_list_type<T: ListElement>() -> DataType {
+    DataType::new_list(T::DATA_TYPE, true)
+}
+
+impl ListElement for i64 {
+    type Builder = Int64Builder;
+
+    const DATA_TYPE: DataType = DataType::Int64;
+
+    fn read(jiter: &mut Jiter, peek: Peek) -> Result<Option<Self>, JiterError> {
+        if peek.is_num() {
+            match jiter.known_number(peek)? {
+                NumberAny::Int(NumberInt::Int(i)) => Ok(Some(i)),
+                NumberAny::Int(NumberInt::BigInt(_)) | NumberAny::Float(_) => Ok(None),
+            }
+        } else {
+            jiter.known_skip(peek).map(|()| None)
+        }
+    }
+}
+
+impl ListElement for f64 {
+    type Builder = Float64Builder;
+
+    const DATA_TYPE: DataType = DataType::Float64;
+
+    fn read(jiter: &mut Jiter, peek: Peek) -> Result<Option<Self>, JiterError> {
+        if peek.is_num() {
+            jiter.known_float(peek).map(Some)
+        } else {
+            jiter.known_skip(peek).map(|()| None)
+        }
+    }
+}
+
+impl ListElement for String {
+    type Builder = StringBuilder;
+
+    const DATA_TYPE: DataType = DataType::Utf8;
+
+    fn read(jiter: &mut Jiter, peek: Peek) -> Result<Option<Self>, JiterError> {
+        if peek == Peek::String {
+            jiter.known_str().map(|s| Some(s.to_owned()))
+        } else {
+            jiter.known_skip(peek).map(|()| None)
+        }
+    }
+}
+
+impl ListElement for bool {
+    type Builder = BooleanBuilder;
+
+    const DATA_TYPE: DataType = DataType::Boolean;
+
+    fn read(jiter: &mut Jiter, peek: Peek) -> Result<Option<Self>, JiterError> {
+        match peek {
+            Peek::True | Peek::False => jiter.known_bool(peek).map(Some),
+            _ => jiter.known_skip(peek).map(|()| None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct BuildTypedList<T>(PhantomData<T>);
+
+impl<T: ListElement> InvokeResult for BuildTypedList<T> {
+    type Item = Vec<Option<T>>;
+
+    type Builder = ListBuilder<T::Builder>;
+
+    // lists aren't primitive, so `return_type_check` declares a dictionary for dictionary inputs
+    const ACCEPT_DICT_RETURN: bool = true;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        ListBuilder::with_capacity(T::Builder::default(), capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.append_option(value);
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        let mut builder = Self::builder(1);
+        Self::append_value(&mut builder, value);
+        ScalarValue::List(Arc::new(builder.finish()))
+    }
+}
+
+pub(crate) fn jiter_json_get_typed_array<T: ListElement>(
+    opt_json: Option<&str>,
+    path: &[JsonPath],
+) -> Result<Vec<Option<T>>, GetError> {
+    let Some((mut jiter, Peek::Array)) = jiter_json_find(opt_json, path) else {
+        return get_err!();
+    };
+    let mut peek_opt = jiter.known_array()?;
+    let mut array_items = Vec::new();
+    while let Some(element_peek) = peek_opt {
+        array_items.push(T::read(&mut jiter, element_peek)?);
+        peek_opt = jiter.array_step()?;
+    }
+    Ok(array_items)
+}
+
+/// Defines a typed array UDF, which differ only in their name and element type.
+macro_rules! make_typed_array_udf {
+    ($udf_impl:ident, $expr_fn_name:ident, $element:ty, $doc:expr) => {
+        make_udf_function!($udf_impl, $expr_fn_name, json_data path, $doc);
+
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        pub(super) struct $udf_impl {
+            signature: Signature,
+            aliases: [String; 1],
+        }
+
+        impl Default for $udf_impl {
+            fn default() -> Self {
+                Self {
+                    signature: Signature::variadic_any(Volatility::Immutable),
+                    aliases: [stringify!($expr_fn_name).to_string()],
+                }
+            }
+        }
+
+        impl ScalarUDFImpl for $udf_impl {
+            fn name(&self) -> &str {
+                self.aliases[0].as_str()
+            }
+
+            fn signature(&self) -> &Signature {
+                &self.signature
+            }
+
+            fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+                return_type_check(arg_types, self.name(), typed_list_type::<$element>())
+            }
+
+            fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+                invoke::<BuildTypedList<$element>>(&args.args, jiter_json_get_typed_array::<$element>)
+            }
+
+            fn aliases(&self) -> &[String] {
+                &self.aliases
+            }
+
+            fn placement(
+                &self,
+                args: &[datafusion::logical_expr::ExpressionPlacement],
+            ) -> datafusion::logical_expr::ExpressionPlacement {
+                // If the first argument is a column and the remaining arguments are literals (a path)
+                // then we can push this UDF down to the leaf nodes.
+                if args.len() >= 2
+                    && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+                    && args[1..]
+                        .iter()
+                        .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+                {
+                    datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+                } else {
+                    datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+                }
+            }
+        }
+    };
+}
+
+make_typed_array_udf!(
+    JsonGetIntArray,
+    json_get_int_array,
+    i64,
+    r#"Get an array of integers from a JSON string by its "path", elements of other types are null"#
+);
+
+make_typed_array_udf!(
+    JsonGetFloatArray,
+    json_get_float_array,
+    f64,
+    r#"Get an array of floats from a JSON string by its "path", elements of other types are null"#
+);
+
+make_typed_array_udf!(
+    JsonGetStrArray,
+    json_get_str_array,
+    String,
+    r#"Get an array of strings from a JSON string by its "path", elements of other types are null"#
+);
+
+make_typed_array_udf!(
+    JsonGetBoolArray,
+    json_get_bool_array,
+    bool,
+    r#"Get an array of booleans from a JSON string by its "path", elements of other types are null"#
+);
@@ -69,7 +69,7 @@ impl ScalarUDFImpl for JsonGetBool {
     }
 }
 
-fn jiter_json_get_bool(json_data: Option<&str>, path: &[JsonPath]) -> Result<bool, GetError> {
+pub(crate) fn jiter_json_get_bool(json_data: Option<&str>, path: &[JsonPath]) -> Result<bool, GetError> {
     if let Some((mut jiter, peek)) = jiter_json_find(json_data, path) {
         match peek {
             Peek::True | Peek::False => Ok(jiter.known_bool(peek)?),
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use datafusion::arrow::array::{ArrayRef, Date32Array, Date32Builder};
+use datafusion::arrow::compute::kernels::cast_utils::string_to_datetime;
+use datafusion::arrow::datatypes::{DataType, Date32Type, Field, FieldRef};
+use datafusion::common::{internal_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+use crate::common::{invoke, return_type_check, without_options, InvokeResult};
+use crate::common_macros::make_udf_function;
+use crate::common_temporal::{
+    jiter_json_get_temporal, number_to_nanos, temporal_options, TemporalOptions, TemporalValue, NO_OPTIONS,
+};
+
+make_udf_function!(
+    JsonGetDate,
+    json_get_date,
+    json_data path,
+    r#"Get a date from a JSON string by its "path""#
+);
+
+make_udf_function!(
+    JsonGetDate,
+    json_get_date_with,
+    json_data format unit path,
+    r#"Get a date from a JSON string by its "path", with a format for strings and a unit for numbers"#,
+    JsonGetDate::with_options()
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonGetDate {
+    signature: Signature,
+    aliases: [String; 1],
+    /// The options which follow the JSON argument
+    options: &'static [&'static str],
+}
+
+impl Default for JsonGetDate {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_date".to_string()],
+            options: NO_OPTIONS,
+        }
+    }
+}
+
+impl JsonGetDate {
+    fn with_options() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_date_with".to_string()],
+            options: &["format", "unit"],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetDate {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        temporal_options(self.name(), args.scalar_arguments, self.options)?;
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        let arg_types = without_options(&arg_types, self.options.len());
+        let return_type = return_type_check(&arg_types, self.name(), DataType::Date32)?;
+        Ok(Arc::new(Field::new(self.name(), return_type, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let scalar_args: Vec<Option<&ScalarValue>> = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(scalar) => Some(scalar),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect();
+        let options = temporal_options(self.name(), &scalar_args, self.options)?;
+        invoke::<Date32Array>(&without_options(&args.args, self.options.len()), |json, path| {
+            jiter_json_get_temporal(json, path, |value| date_days(&value, &options))
+        })
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (options and a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+const NANOS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// Strings are dates, or timestamps whose date is used, numbers are timestamps in the `unit` option.
+pub(crate) fn date_days(value: &TemporalValue, options: &TemporalOptions) -> Option<i32> {
+    let date = match (value, options.format.as_deref()) {
+        (TemporalValue::Str(s), Some(format)) => NaiveDate::parse_from_str(s, format)
+            .or_else(|_| NaiveDateTime::parse_from_str(s, format).map(|datetime| datetime.date()))
+            .ok(),
+        (TemporalValue::Str(s), None) => s
+            .parse::<NaiveDate>()
+            .or_else(|_| DateTime::parse_from_rfc3339(s).map(|datetime| datetime.date_naive()))
+            .ok()
+            .or_else(|| Some(string_to_datetime(&Utc, s).ok()?.date_naive())),
+        (TemporalValue::Int(_) | TemporalValue::Float(_), _) => None,
+    };
+    match date {
+        Some(date) => Some(Date32Type::from_naive_date(date)),
+        None => i32::try_from(number_to_nanos(value, options.unit)?.div_euclid(NANOS_PER_DAY)).ok(),
+    }
+}
+
+impl InvokeResult for Date32Array {
+    type Item = i32;
+
+    type Builder = Date32Builder;
+
+    // Cheaper to return a date array rather than dict-encoded dates
+    const ACCEPT_DICT_RETURN: bool = false;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        Date32Builder::with_capacity(capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.append_option(value);
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        ScalarValue::Date32(value)
+    }
+}
@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::ArrowNativeTypeOp;
+use datafusion::arrow::array::{
+    ArrayRef, AsArray, Decimal128Array, Decimal128Builder, Decimal256Array, Decimal256Builder,
+};
+use datafusion::arrow::compute::kernels::cast_utils::parse_decimal;
+use datafusion::arrow::datatypes::{
+    i256, DataType, Decimal128Type, Decimal256Type, DecimalType, Field, FieldRef, DECIMAL128_MAX_PRECISION,
+    DECIMAL256_MAX_PRECISION, DECIMAL_DEFAULT_SCALE,
+};
+use datafusion::arrow::error::ArrowError;
+use datafusion::common::{exec_err, internal_err, plan_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use jiter::{Jiter, JiterError, Peek};
+
+use crate::common::{get_err, invoke, jiter_json_find, return_type_check, GetError, InvokeResult, JsonPath};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonGetDecimal,
+    json_get_decimal,
+    json_data path precision scale,
+    r#"Get an exact decimal value from a JSON string by its "path", with the given precision and scale"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonGetDecimal {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonGetDecimal {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_decimal".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetDecimal {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let data_type = return_decimal_type(self.name(), args.scalar_arguments)?;
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        let return_type = return_type_check(&arg_types, self.name(), data_type)?;
+        Ok(Arc::new(Field::new(self.name(), return_type, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let scalar_args: Vec<Option<&ScalarValue>> = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(scalar) => Some(scalar),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect();
+        let data_type = return_decimal_type(self.name(), &scalar_args)?;
+        // the precision and scale are the last two arguments, everything before them is the JSON and path
+        let json_and_path = &args.args[..args.args.len() - 2];
+        match data_type {
+            DataType::Decimal128(precision, scale) => {
+                let result = invoke::<Decimal128Array>(json_and_path, |json, path| {
+                    jiter_json_get_decimal::<Decimal128Type>(json, path, precision, scale)
+                })?;
+                with_precision_and_scale::<Decimal128Type>(result, precision, scale)
+            }
+            DataType::Decimal256(precision, scale) => {
+                let result = invoke::<Decimal256Array>(json_and_path, |json, path| {
+                    jiter_json_get_decimal::<Decimal256Type>(json, path, precision, scale)
+                })?;
+                with_precision_and_scale::<Decimal256Type>(result, precision, scale)
+            }
+            other => exec_err!("unexpected decimal type {other:?}"),
+        }
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path, precision and scale)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// The decimal type for a precision and scale, `Decimal128` where the precision allows, otherwise `Decimal256`.
+pub(crate) fn decimal_type(precision: u8, scale: i8) -> DataType {
+    if precision <= DECIMAL128_MAX_PRECISION {
+        DataType::Decimal128(precision, scale)
+    } else {
+        DataType::Decimal256(precision, scale)
+    }
+}
+
+/// Get the return type from the literal precision and scale, which are the last two arguments.
+fn return_decimal_type(fn_name: &str, scalar_args: &[Option<&ScalarValue>]) -> DataFusionResult<DataType> {
+    let [_, .., Some(precision), Some(scale)] = scalar_args else {
+        return plan_err!(
+            "The '{fn_name}' function requires a JSON argument, an optional path, then a literal precision and scale."
+        );
+    };
+    let (Some(precision), Some(scale)) = (literal_int(precision), literal_int(scale)) else {
+        return plan_err!("The precision and scale of '{fn_name}' must be integer literals.");
+    };
+    match (u8::try_from(precision), i8::try_from(scale)) {
+        (Ok(precision @ 1..=DECIMAL256_MAX_PRECISION), Ok(scale)) if i16::from(scale) <= i16::from(precision) => {
+            Ok(decimal_type(precision, scale))
+        }
+        _ => plan_err!(
+            "Invalid precision {precision} and scale {scale} for '{fn_name}', the precision must be between 1 and \
+             {DECIMAL256_MAX_PRECISION} and the scale can't be greater than the precision."
+        ),
+    }
+}
+
+fn literal_int(scalar: &ScalarValue) -> Option<i64> {
+    if !scalar.data_type().is_integer() {
+        return None;
+    }
+    match scalar.cast_to(&DataType::Int64) {
+        Ok(ScalarValue::Int64(value)) => value,
+        _ => None,
+    }
+}
+
+/// `invoke` builds decimals with the default precision and scale, set the ones the values were parsed with.
+fn with_precision_and_scale<T: DecimalType>(
+    value: ColumnarValue,
+    precision: u8,
+    scale: i8,
+) -> DataFusionResult<ColumnarValue> {
+    let relabel = |array: &ArrayRef| -> DataFusionResult<ArrayRef> {
+        let array = array
+            .as_primitive::<T>()
+            .clone()
+            .with_precision_and_scale(precision, scale)?;
+        Ok(Arc::new(array))
+    };
+    match value {
+        ColumnarValue::Array(array) => relabel(&array).map(ColumnarValue::Array),
+        ColumnarValue::Scalar(scalar) => {
+            ScalarValue::try_from_array(&relabel(&scalar.to_array()?)?, 0).map(ColumnarValue::Scalar)
+        }
+    }
+}
+
+impl InvokeResult for Decimal128Array {
+    type Item = i128;
+
+    type Builder = Decimal128Builder;
+
+    // Cheaper to return a decimal array rather than dict-encoded decimals
+    const ACCEPT_DICT_RETURN: bool = false;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        Decimal128Builder::with_capacity(capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.append_option(value);
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        ScalarValue::Decimal128(value, DECIMAL128_MAX_PRECISION, DECIMAL_DEFAULT_SCALE)
+    }
+}
+
+impl InvokeResult for Decimal256Array {
+    type Item = i256;
+
+    type Builder = Decimal256Builder;
+
+    // Cheaper to return a decimal array rather than dict-encoded decimals
+    const ACCEPT_DICT_RETURN: bool = false;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        Decimal256Builder::with_capacity(capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.append_option(value);
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        ScalarValue::Decimal256(value, DECIMAL256_MAX_PRECISION, DECIMAL_DEFAULT_SCALE)
+    }
+}
+
+/// Parse the number (or numeric string) at `path` straight from its text, so it's only rounded to `scale`.
+fn jiter_json_get_decimal<T: DecimalType>(
+    json_data: Option<&str>,
+    path: &[JsonPath],
+    precision: u8,
+    scale: i8,
+) -> Result<T::Native, GetError> {
+    let Some((mut jiter, peek)) = jiter_json_find(json_data, path) else {
+        return get_err!();
+    };
+    let Some(text) = read_decimal_text(&mut jiter, peek)? else {
+        return get_err!();
+    };
+    parse_decimal_rounded::<T>(text, precision, scale).map_err(|_| GetError)
+}
+
+/// Parse `text` like [`parse_decimal`], but round half away from zero at `scale` rather than truncating.
+pub(crate) fn parse_decimal_rounded<T: DecimalType>(
+    text: &str,
+    precision: u8,
+    scale: i8,
+) -> Result<T::Native, ArrowError> {
+    let value = parse_decimal::<T>(text, precision, scale)?;
+    if first_dropped_digit(text, scale).is_none_or(|digit| digit < b'5') {
+        return Ok(value);
+    }
+    let rounded = if text.starts_with('-') {
+        value.sub_checked(T::Native::ONE)?
+    } else {
+        value.add_checked(T::Native::ONE)?
+    };
+    T::validate_decimal_precision(rounded, precision, scale)?;
+    Ok(rounded)
+}
+
+/// The first digit of the decimal `text` which doesn't fit in `scale`, taking any exponent into account.
+fn first_dropped_digit(text: &str, scale: i8) -> Option<u8> {
+    let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let point = i64::try_from(integer.len()).ok()? + exponent;
+    let index = usize::try_from(point + i64::from(scale)).ok()?;
+    integer.bytes().chain(fraction.bytes()).nth(index)
+}
+
+/// Read the text of the value starting at `peek` if it's a number or string, other values are skipped.
+pub(crate) fn read_decimal_text<'j>(jiter: &'j mut Jiter, peek: Peek) -> Result<Option<&'j str>, JiterError> {
+    match peek {
+        Peek::String => jiter.known_str().map(Some),
+        Peek::Infinity | Peek::NaN => jiter.known_skip(peek).map(|()| None),
+        _ if peek.is_num() => {
+            let start = jiter.current_index();
+            jiter.known_number(peek)?;
+            Ok(std::str::from_utf8(jiter.slice_to_current(start)).ok())
+        }
+        _ => jiter.known_skip(peek).map(|()| None),
+    }
+}
@@ -96,7 +96,7 @@ impl InvokeResult for Float64Array {
     }
 }
 
-fn jiter_json_get_float(json_data: Option<&str>, path: &[JsonPath]) -> Result<f64, GetError> {
+pub(crate) fn jiter_json_get_float(json_data: Option<&str>, path: &[JsonPath]) -> Result<f64, GetError> {
     if let Some((mut jiter, peek)) = jiter_json_find(json_data, path) {
         match peek {
             Peek::String => {
@@ -104,14 +104,9 @@ fn jiter_json_get_float(json_data: Option<&str>, path: &[JsonPath]) -> Result<f6
                 s.parse::<f64>().map_err(|_| GetError)
             }
             // numbers are represented by everything else in peek, hence doing it this way
-            Peek::Null
-            | Peek::True
-            | Peek::False
-            | Peek::Minus
-            | Peek::Infinity
-            | Peek::NaN
-            | Peek::Array
-            | Peek::Object => get_err!(),
+            Peek::Null | Peek::True | Peek::False | Peek::Infinity | Peek::NaN | Peek::Array | Peek::Object => {
+                get_err!()
+            }
             _ => match jiter.known_number(peek)? {
                 NumberAny::Float(f) => Ok(f),
                 NumberAny::Int(int) => Ok(int.into()),
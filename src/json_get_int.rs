@@ -96,21 +96,16 @@ impl InvokeResult for Int64Array {
     }
 }
 
-fn jiter_json_get_int(json_data: Option<&str>, path: &[JsonPath]) -> Result<i64, GetError> {
+pub(crate) fn jiter_json_get_int(json_data: Option<&str>, path: &[JsonPath]) -> Result<i64, GetError> {
     if let Some((mut jiter, peek)) = jiter_json_find(json_data, path) {
         match peek {
             Peek::String => {
                 let s = jiter.known_str()?;
                 s.parse::<i64>().map_err(|_| GetError)
             }
-            Peek::Null
-            | Peek::True
-            | Peek::False
-            | Peek::Minus
-            | Peek::Infinity
-            | Peek::NaN
-            | Peek::Array
-            | Peek::Object => get_err!(),
+            Peek::Null | Peek::True | Peek::False | Peek::Infinity | Peek::NaN | Peek::Array | Peek::Object => {
+                get_err!()
+            }
             _ => match jiter.known_int(peek)? {
                 NumberInt::Int(i) => Ok(i),
                 NumberInt::BigInt(_) => get_err!(),
@@ -0,0 +1,244 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{ArrayRef, MapBuilder, MapFieldNames, StringBuilder};
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion::common::{internal_err, plan_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use jiter::Peek;
+
+use crate::common::{
+    get_err, invoke, jiter_json_find, return_type_check, without_options, GetError, InvokeResult, JsonPath,
+};
+use crate::common_macros::make_udf_function;
+use crate::common_union::json_field_metadata;
+
+make_udf_function!(
+    JsonGetMap,
+    json_get_map,
+    json_data path,
+    r#"Get a JSON object as a map of its string values from a JSON string by its "path""#
+);
+
+make_udf_function!(
+    JsonGetMap,
+    json_get_map_with,
+    json_data values path,
+    r#"Get a JSON object as a map from a JSON string by its "path", with values 'str' for strings or 'json' for raw JSON"#,
+    JsonGetMap::with_options()
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonGetMap {
+    signature: Signature,
+    aliases: [String; 1],
+    /// Whether the `values` option follows the JSON argument
+    values_option: bool,
+}
+
+impl Default for JsonGetMap {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_map".to_string()],
+            values_option: false,
+        }
+    }
+}
+
+impl JsonGetMap {
+    fn with_options() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_map_with".to_string()],
+            values_option: true,
+        }
+    }
+
+    /// Whether values are kept as raw JSON.
+    fn raw_json(&self, scalar_args: &[Option<&ScalarValue>]) -> DataFusionResult<bool> {
+        if !self.values_option {
+            return Ok(false);
+        }
+        let fn_name = self.name();
+        let Some(values) = scalar_args.get(1) else {
+            return plan_err!(
+                "The '{fn_name}' function requires a JSON argument, the values option, then an optional path."
+            );
+        };
+        match values {
+            Some(
+                ScalarValue::Utf8(Some(values))
+                | ScalarValue::Utf8View(Some(values))
+                | ScalarValue::LargeUtf8(Some(values)),
+            ) => match values.as_str() {
+                "str" => Ok(false),
+                "json" => Ok(true),
+                _ => plan_err!("Unknown values '{values}' for '{fn_name}', expected 'str' or 'json'."),
+            },
+            Some(scalar) if scalar.is_null() => Ok(false),
+            _ => plan_err!("The 'values' option of '{fn_name}' must be a string literal or null."),
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetMap {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let raw_json = self.raw_json(args.scalar_arguments)?;
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        let arg_types = without_options(&arg_types, usize::from(self.values_option));
+        let return_type = return_type_check(&arg_types, self.name(), map_type(raw_json))?;
+        Ok(Arc::new(Field::new(self.name(), return_type, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let scalar_args: Vec<Option<&ScalarValue>> = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(scalar) => Some(scalar),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect();
+        let raw_json = self.raw_json(&scalar_args)?;
+        let json_and_path = without_options(&args.args, usize::from(self.values_option));
+        if raw_json {
+            invoke::<BuildMap<true>>(&json_and_path, |json, path| jiter_json_get_map(json, path, true))
+        } else {
+            invoke::<BuildMap<false>>(&json_and_path, |json, path| jiter_json_get_map(json, path, false))
+        }
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (an option and a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+fn map_field_names() -> MapFieldNames {
+    MapFieldNames {
+        entry: "entries".to_string(),
+        key: "key".to_string(),
+        value: "value".to_string(),
+    }
+}
+
+/// Raw JSON values are marked with the JSON extension metadata, like the items of `json_get_array`.
+fn value_field(raw_json: bool) -> Field {
+    let field = Field::new("value", DataType::Utf8, true);
+    if raw_json {
+        field.with_metadata(json_field_metadata())
+    } else {
+        field
+    }
+}
+
+fn map_type(raw_json: bool) -> DataType {
+    let entries = Fields::from(vec![Field::new("key", DataType::Utf8, false), value_field(raw_json)]);
+    DataType::Map(Arc::new(Field::new("entries", DataType::Struct(entries), false)), false)
+}
+
+/// Builds a `MapArray` of string values, raw JSON if `RAW_JSON`, otherwise decoded strings.
+#[derive(Debug)]
+struct BuildMap<const RAW_JSON: bool>;
+
+impl<const RAW_JSON: bool> InvokeResult for BuildMap<RAW_JSON> {
+    type Item = Vec<(String, Option<String>)>;
+
+    type Builder = MapBuilder<StringBuilder, StringBuilder>;
+
+    const ACCEPT_DICT_RETURN: bool = true;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        MapBuilder::with_capacity(
+            Some(map_field_names()),
+            StringBuilder::new(),
+            StringBuilder::new(),
+            capacity,
+        )
+        .with_values_field(value_field(RAW_JSON))
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        if let Some(entries) = value {
+            for (key, value) in entries {
+                builder.keys().append_value(key);
+                builder.values().append_option(value);
+            }
+            builder.append(true).expect("map keys and values have the same length");
+        } else {
+            builder.append(false).expect("map keys and values have the same length");
+        }
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        let mut builder = Self::builder(1);
+        Self::append_value(&mut builder, value);
+        ScalarValue::Map(Arc::new(builder.finish()))
+    }
+}
+
+/// Get the entries of the object at `path`, values are either the raw JSON or decoded strings,
+/// in which case non-string values are null.
+fn jiter_json_get_map(
+    opt_json: Option<&str>,
+    path: &[JsonPath],
+    raw_json: bool,
+) -> Result<Vec<(String, Option<String>)>, GetError> {
+    let Some((mut jiter, Peek::Object)) = jiter_json_find(opt_json, path) else {
+        return get_err!();
+    };
+    let mut entries = Vec::new();
+    let mut opt_key = jiter.known_object()?;
+    while let Some(key) = opt_key {
+        let key = key.to_string();
+        let peek = jiter.peek()?;
+        let value = if raw_json {
+            let start = jiter.current_index();
+            jiter.known_skip(peek)?;
+            Some(std::str::from_utf8(jiter.slice_to_current(start))?.to_string())
+        } else if peek == Peek::String {
+            Some(jiter.known_str()?.to_string())
+        } else {
+            jiter.known_skip(peek)?;
+            None
+        };
+        entries.push((key, value));
+        opt_key = jiter.next_key()?;
+    }
+    Ok(entries)
+}
@@ -69,7 +69,7 @@ impl ScalarUDFImpl for JsonGetStr {
     }
 }
 
-fn jiter_json_get_str(json_data: Option<&str>, path: &[JsonPath]) -> Result<String, GetError> {
+pub(crate) fn jiter_json_get_str(json_data: Option<&str>, path: &[JsonPath]) -> Result<String, GetError> {
     if let Some((mut jiter, peek)) = jiter_json_find(json_data, path) {
         match peek {
             Peek::String => Ok(jiter.known_str()?.to_owned()),
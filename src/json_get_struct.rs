@@ -0,0 +1,427 @@
+use std::sync::{Arc, OnceLock};
+
+use datafusion::arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray, StructArray};
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion::common::{internal_err, plan_err, Result as DataFusionResult, ScalarValue};
+use datafusion::functions::core::getfield::GetFieldFunc;
+use datafusion::logical_expr::{
+    ColumnarValue, Expr, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Signature, Volatility,
+};
+use jiter::{Jiter, Peek};
+
+use crate::common::{
+    find_with_slices, json_values, map_dictionary_values, return_type_check, scalar_path, GetError, InvokeResult,
+    JsonPath,
+};
+use crate::common_macros::make_udf_function;
+use crate::common_union::{json_field_metadata, JsonUnion};
+use crate::json_as_text::jiter_json_as_text;
+use crate::json_get::jiter_json_get_union;
+use crate::json_get_bool::jiter_json_get_bool;
+use crate::json_get_float::jiter_json_get_float;
+use crate::json_get_int::jiter_json_get_int;
+use crate::json_get_json::jiter_json_get_json;
+use crate::json_get_str::jiter_json_get_str;
+
+make_udf_function!(
+    JsonGetStruct,
+    json_get_struct,
+    json_data paths_and_types,
+    r#"Get several values from a JSON string in one pass, returns a struct with a field `c0`, `c1`, ... for each "path" and type pair"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonGetStruct {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonGetStruct {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_struct".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetStruct {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let field_types = field_types(self.name(), args.scalar_arguments)?;
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        let return_type = return_type_check(&arg_types, self.name(), DataType::Struct(struct_fields(&field_types)))?;
+        Ok(Arc::new(Field::new(self.name(), return_type, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let scalar_args: Vec<Option<&ScalarValue>> = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(scalar) => Some(scalar),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect();
+        let field_types = field_types(self.name(), &scalar_args)?;
+        let fields = struct_fields(&field_types);
+        let paths = args.args[1..]
+            .iter()
+            .step_by(2)
+            .map(|arg| scalar_path(std::slice::from_ref(arg)))
+            .collect::<DataFusionResult<Vec<_>>>()?;
+
+        match &args.args[0] {
+            ColumnarValue::Array(json_array) => {
+                extract_struct(json_array, &fields, &field_types, &paths).map(ColumnarValue::Array)
+            }
+            ColumnarValue::Scalar(scalar) => {
+                let array = extract_struct(&scalar.to_array()?, &fields, &field_types, &paths)?;
+                ScalarValue::try_from_array(&array, 0).map(ColumnarValue::Scalar)
+            }
+        }
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        _args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // unlike the other functions this is never moved towards the leaf nodes, since merging the projection it's in
+        // would inline it into each field read from it, and parse each document once per field rather than once
+        datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+    }
+}
+
+/// `get_field` for the fields of a `json_get_struct` call, used by [`JsonGetMergeRule`] to read the merged values.
+///
+/// `get_field` is moved towards the leaf nodes, and so would inline `json_get_struct` into each field read from
+/// it, this is kept in place so the struct is only evaluated once.
+///
+/// [`JsonGetMergeRule`]: crate::JsonGetMergeRule
+#[derive(Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct JsonStructField {
+    get_field: GetFieldFunc,
+}
+
+impl JsonStructField {
+    /// The field `name` of `json_struct`.
+    pub(crate) fn call(json_struct: Expr, name: String) -> Expr {
+        static UDF: OnceLock<Arc<ScalarUDF>> = OnceLock::new();
+        let udf = UDF.get_or_init(|| Arc::new(ScalarUDF::new_from_impl(Self::default())));
+        udf.call(vec![json_struct, Expr::Literal(ScalarValue::Utf8(Some(name)), None)])
+    }
+}
+
+impl ScalarUDFImpl for JsonStructField {
+    fn name(&self) -> &'static str {
+        "json_struct_field"
+    }
+
+    fn signature(&self) -> &Signature {
+        self.get_field.signature()
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        self.get_field.return_type(arg_types)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> DataFusionResult<Vec<DataType>> {
+        self.get_field.coerce_types(arg_types)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        self.get_field.return_field_from_args(args)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        self.get_field.invoke_with_args(args)
+    }
+}
+
+/// The type of a struct field, named after the `json_get*` function with the same behaviour.
+#[derive(Debug, Clone, Copy)]
+enum FieldType {
+    /// `json_get`
+    Any,
+    /// `json_get_str`
+    Str,
+    /// `json_get_int`
+    Int,
+    /// `json_get_float`
+    Float,
+    /// `json_get_bool`
+    Bool,
+    /// `json_get_json`
+    Json,
+    /// `json_as_text`
+    Text,
+}
+
+impl FieldType {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "any" => Some(Self::Any),
+            "str" => Some(Self::Str),
+            "int" => Some(Self::Int),
+            "float" => Some(Self::Float),
+            "bool" => Some(Self::Bool),
+            "json" => Some(Self::Json),
+            "text" => Some(Self::Text),
+            _ => None,
+        }
+    }
+
+    fn field(self, name: String) -> Field {
+        match self {
+            Self::Any => Field::new(name, JsonUnion::data_type(), true),
+            Self::Str | Self::Text => Field::new(name, DataType::Utf8, true),
+            Self::Int => Field::new(name, DataType::Int64, true),
+            Self::Float => Field::new(name, DataType::Float64, true),
+            Self::Bool => Field::new(name, DataType::Boolean, true),
+            Self::Json => Field::new(name, DataType::Utf8, true).with_metadata(json_field_metadata()),
+        }
+    }
+
+    fn builder(self, capacity: usize) -> Box<dyn FieldBuilder> {
+        match self {
+            Self::Any => TypedFieldBuilder::<JsonUnion>::boxed(capacity, jiter_json_get_union),
+            Self::Str => TypedFieldBuilder::<StringArray>::boxed(capacity, jiter_json_get_str),
+            Self::Int => TypedFieldBuilder::<Int64Array>::boxed(capacity, jiter_json_get_int),
+            Self::Float => TypedFieldBuilder::<Float64Array>::boxed(capacity, jiter_json_get_float),
+            Self::Bool => TypedFieldBuilder::<BooleanArray>::boxed(capacity, jiter_json_get_bool),
+            Self::Json => TypedFieldBuilder::<StringArray>::boxed(capacity, jiter_json_get_json),
+            Self::Text => TypedFieldBuilder::<StringArray>::boxed(capacity, jiter_json_as_text),
+        }
+    }
+}
+
+/// The type of each field from the literal type arguments, which follow each path.
+fn field_types(fn_name: &str, scalar_args: &[Option<&ScalarValue>]) -> DataFusionResult<Vec<FieldType>> {
+    if scalar_args.len() < 3 || scalar_args.len().is_multiple_of(2) {
+        return plan_err!("The '{fn_name}' function requires a JSON argument followed by pairs of path and type.");
+    }
+    scalar_args[2..]
+        .iter()
+        .step_by(2)
+        .enumerate()
+        .map(|(index, arg)| {
+            let Some(
+                ScalarValue::Utf8(Some(name)) | ScalarValue::Utf8View(Some(name)) | ScalarValue::LargeUtf8(Some(name)),
+            ) = arg
+            else {
+                return plan_err!(
+                    "Unexpected argument to '{fn_name}' at position {}, expected a literal type name.",
+                    index * 2 + 3
+                );
+            };
+            let Some(field_type) = FieldType::from_name(name) else {
+                return plan_err!(
+                    "Unknown type '{name}' for '{fn_name}' at position {}, expected one of 'any', 'str', 'int', \
+                     'float', 'bool', 'json' or 'text'.",
+                    index * 2 + 3
+                );
+            };
+            Ok(field_type)
+        })
+        .collect()
+}
+
+fn struct_fields(field_types: &[FieldType]) -> Fields {
+    field_types
+        .iter()
+        .enumerate()
+        .map(|(index, field_type)| field_type.field(format!("c{index}")))
+        .collect()
+}
+
+fn extract_struct(
+    json_array: &ArrayRef,
+    fields: &Fields,
+    field_types: &[FieldType],
+    paths: &[Vec<JsonPath>],
+) -> DataFusionResult<ArrayRef> {
+    map_dictionary_values(json_array, |json_array| {
+        extract_struct_values(json_array, fields, field_types, paths)
+    })
+}
+
+fn extract_struct_values(
+    json_array: &ArrayRef,
+    fields: &Fields,
+    field_types: &[FieldType],
+    paths: &[Vec<JsonPath>],
+) -> DataFusionResult<ArrayRef> {
+    let json_values = json_values(json_array)?;
+
+    let mut tree = PathTree::default();
+    let in_tree: Vec<bool> = paths
+        .iter()
+        .enumerate()
+        .map(|(field, path)| tree.insert(path, field))
+        .collect();
+
+    let mut builders: Vec<_> = field_types.iter().map(|t| t.builder(json_values.len())).collect();
+    let mut found = vec![None; paths.len()];
+    for opt_json in json_values {
+        found.fill(None);
+        if let Some(json) = opt_json {
+            tree.walk(json.as_bytes(), 0, &mut found);
+        }
+        for (field, builder) in builders.iter_mut().enumerate() {
+            if !in_tree[field] {
+                builder.append(opt_json, &paths[field]);
+            } else if let (Some(json), Some(offset)) = (opt_json, found[field]) {
+                builder.append(json.get(offset..), &[]);
+            } else {
+                builder.append(None, &[]);
+            }
+        }
+    }
+
+    let columns = builders
+        .into_iter()
+        .map(FieldBuilder::finish)
+        .collect::<DataFusionResult<Vec<_>>>()?;
+    Ok(Arc::new(StructArray::try_new(fields.clone(), columns, None)?))
+}
+
+/// The paths which only look up object keys and array indices, merged into a tree so each document can be
+/// walked once to find all of them.
+#[derive(Default)]
+struct PathTree<'p> {
+    /// fields whose path ends at this node
+    fields: Vec<usize>,
+    keys: Vec<(&'p str, PathTree<'p>)>,
+    indices: Vec<(usize, PathTree<'p>)>,
+}
+
+impl<'p> PathTree<'p> {
+    /// Add the path for `field` to the tree, returns `false` if it can't be found by walking the tree.
+    fn insert(&mut self, path: &'p [JsonPath], field: usize) -> bool {
+        if !path
+            .iter()
+            .all(|element| matches!(element, JsonPath::Key(_) | JsonPath::Index(_)))
+        {
+            return false;
+        }
+        let mut node = self;
+        for element in path {
+            node = match element {
+                JsonPath::Key(key) => node.key_child(key),
+                JsonPath::Index(index) => node.index_child(*index),
+                _ => return false,
+            };
+        }
+        node.fields.push(field);
+        true
+    }
+
+    fn key_child(&mut self, key: &'p str) -> &mut Self {
+        let position = self.keys.iter().position(|(k, _)| *k == key).unwrap_or_else(|| {
+            self.keys.push((key, Self::default()));
+            self.keys.len() - 1
+        });
+        &mut self.keys[position].1
+    }
+
+    fn index_child(&mut self, index: usize) -> &mut Self {
+        let position = self.indices.iter().position(|(i, _)| *i == index).unwrap_or_else(|| {
+            self.indices.push((index, Self::default()));
+            self.indices.len() - 1
+        });
+        &mut self.indices[position].1
+    }
+
+    /// Record the offset in `found` of the value at `offset` for each field ending here, then walk into the
+    /// children. Like [`crate::common::jiter_json_find`], the first of any duplicate keys is used.
+    fn walk(&self, json: &[u8], offset: usize, found: &mut [Option<usize>]) -> Option<()> {
+        for &field in &self.fields {
+            found[field].get_or_insert(offset);
+        }
+        if self.keys.is_empty() && self.indices.is_empty() {
+            return Some(());
+        }
+        let mut jiter = Jiter::new(json.get(offset..)?);
+        match jiter.peek().ok()? {
+            Peek::Object if !self.keys.is_empty() => {
+                let mut next_key = jiter.known_object().ok()?;
+                while let Some(key) = next_key {
+                    let child = self.keys.iter().find(|(k, _)| *k == key).map(|(_, child)| child);
+                    let peek = jiter.peek().ok()?;
+                    if let Some(child) = child {
+                        child.walk(json, offset + jiter.current_index(), found)?;
+                    }
+                    jiter.known_skip(peek).ok()?;
+                    next_key = jiter.next_key().ok()?;
+                }
+            }
+            Peek::Array if !self.indices.is_empty() => {
+                let mut next_item = jiter.known_array().ok()?;
+                let mut index = 0;
+                while let Some(peek) = next_item {
+                    if let Some((_, child)) = self.indices.iter().find(|(i, _)| *i == index) {
+                        child.walk(json, offset + jiter.current_index(), found)?;
+                    }
+                    jiter.known_skip(peek).ok()?;
+                    next_item = jiter.array_step().ok()?;
+                    index += 1;
+                }
+            }
+            _ => {}
+        }
+        Some(())
+    }
+}
+
+/// Builds the array for one field of the struct.
+trait FieldBuilder {
+    fn append(&mut self, opt_json: Option<&str>, path: &[JsonPath]);
+
+    fn finish(self: Box<Self>) -> DataFusionResult<ArrayRef>;
+}
+
+/// Finds the value of a field at a path, like the `jiter_json_get_*` function of the matching `json_get_*` function.
+type JiterFind<I> = fn(Option<&str>, &[JsonPath]) -> Result<I, GetError>;
+
+struct TypedFieldBuilder<R: InvokeResult> {
+    builder: R::Builder,
+    jiter_find: JiterFind<R::Item>,
+}
+
+impl<R: InvokeResult + 'static> TypedFieldBuilder<R>
+where
+    R::Builder: 'static,
+{
+    fn boxed(capacity: usize, jiter_find: JiterFind<R::Item>) -> Box<dyn FieldBuilder> {
+        Box::new(Self {
+            builder: R::builder(capacity),
+            jiter_find,
+        })
+    }
+}
+
+impl<R: InvokeResult> FieldBuilder for TypedFieldBuilder<R> {
+    fn append(&mut self, opt_json: Option<&str>, path: &[JsonPath]) {
+        let value = find_with_slices(opt_json, path, &self.jiter_find).ok();
+        R::append_value(&mut self.builder, value);
+    }
+
+    fn finish(self: Box<Self>) -> DataFusionResult<ArrayRef> {
+        R::finish(self.builder)
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use chrono::{NaiveTime, Timelike};
+use datafusion::arrow::array::{ArrayRef, Time64NanosecondArray, Time64NanosecondBuilder};
+use datafusion::arrow::compute::kernels::cast_utils::string_to_time_nanoseconds;
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef, TimeUnit};
+use datafusion::common::{internal_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+use crate::common::{invoke, return_type_check, without_options, InvokeResult};
+use crate::common_macros::make_udf_function;
+use crate::common_temporal::{
+    jiter_json_get_temporal, number_to_nanos, temporal_options, TemporalOptions, TemporalValue, NO_OPTIONS,
+};
+
+make_udf_function!(
+    JsonGetTime,
+    json_get_time,
+    json_data path,
+    r#"Get a time of day from a JSON string by its "path""#
+);
+
+make_udf_function!(
+    JsonGetTime,
+    json_get_time_with,
+    json_data format unit path,
+    r#"Get a time of day from a JSON string by its "path", with a format for strings and a unit for numbers"#,
+    JsonGetTime::with_options()
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonGetTime {
+    signature: Signature,
+    aliases: [String; 1],
+    /// The options which follow the JSON argument
+    options: &'static [&'static str],
+}
+
+impl Default for JsonGetTime {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_time".to_string()],
+            options: NO_OPTIONS,
+        }
+    }
+}
+
+impl JsonGetTime {
+    fn with_options() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_time_with".to_string()],
+            options: &["format", "unit"],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetTime {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        temporal_options(self.name(), args.scalar_arguments, self.options)?;
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        let arg_types = without_options(&arg_types, self.options.len());
+        let return_type = return_type_check(&arg_types, self.name(), DataType::Time64(TimeUnit::Nanosecond))?;
+        Ok(Arc::new(Field::new(self.name(), return_type, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let scalar_args: Vec<Option<&ScalarValue>> = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(scalar) => Some(scalar),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect();
+        let options = temporal_options(self.name(), &scalar_args, self.options)?;
+        invoke::<Time64NanosecondArray>(&without_options(&args.args, self.options.len()), |json, path| {
+            jiter_json_get_temporal(json, path, |value| time_nanos(&value, &options))
+        })
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (options and a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+const NANOS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// Strings are times of day, numbers are the time since midnight in the `unit` option.
+fn time_nanos(value: &TemporalValue, options: &TemporalOptions) -> Option<i64> {
+    let nanos = match (value, options.format.as_deref()) {
+        (TemporalValue::Str(s), Some(format)) => NaiveTime::parse_from_str(s, format)
+            .ok()
+            .map(|time| i64::from(time.num_seconds_from_midnight()) * 1_000_000_000 + i64::from(time.nanosecond())),
+        (TemporalValue::Str(s), None) => string_to_time_nanoseconds(s).ok(),
+        (TemporalValue::Int(_) | TemporalValue::Float(_), _) => None,
+    };
+    nanos
+        .or_else(|| number_to_nanos(value, options.unit))
+        .filter(|nanos| (0..NANOS_PER_DAY).contains(nanos))
+}
+
+impl InvokeResult for Time64NanosecondArray {
+    type Item = i64;
+
+    type Builder = Time64NanosecondBuilder;
+
+    // Cheaper to return a time array rather than dict-encoded times
+    const ACCEPT_DICT_RETURN: bool = false;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        Time64NanosecondBuilder::with_capacity(capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.append_option(value);
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        ScalarValue::Time64Nanosecond(value)
+    }
+}
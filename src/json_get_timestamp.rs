@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{ArrayRef, AsArray, TimestampNanosecondArray, TimestampNanosecondBuilder};
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef, TimeUnit, TimestampNanosecondType};
+use datafusion::common::{internal_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+use crate::common::{invoke, return_type_check, without_options, InvokeResult};
+use crate::common_macros::make_udf_function;
+use crate::common_temporal::{
+    jiter_json_get_temporal, number_to_nanos, parse_timestamp_nanos, temporal_options, TemporalOptions, TemporalValue,
+    NO_OPTIONS,
+};
+
+make_udf_function!(
+    JsonGetTimestamp,
+    json_get_timestamp,
+    json_data path,
+    r#"Get a timestamp from a JSON string by its "path""#
+);
+
+make_udf_function!(
+    JsonGetTimestamp,
+    json_get_timestamp_with,
+    json_data format unit tz path,
+    r#"Get a timestamp from a JSON string by its "path", with a format for strings, a unit for numbers and a timezone"#,
+    JsonGetTimestamp::with_options()
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonGetTimestamp {
+    signature: Signature,
+    aliases: [String; 1],
+    /// The options which follow the JSON argument
+    options: &'static [&'static str],
+}
+
+impl Default for JsonGetTimestamp {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_timestamp".to_string()],
+            options: NO_OPTIONS,
+        }
+    }
+}
+
+impl JsonGetTimestamp {
+    fn with_options() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_timestamp_with".to_string()],
+            options: &["format", "unit", "tz"],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetTimestamp {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let options = temporal_options(self.name(), args.scalar_arguments, self.options)?;
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        let arg_types = without_options(&arg_types, self.options.len());
+        let return_type = return_type_check(&arg_types, self.name(), timestamp_type(&options))?;
+        Ok(Arc::new(Field::new(self.name(), return_type, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let scalar_args: Vec<Option<&ScalarValue>> = args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(scalar) => Some(scalar),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect();
+        let options = temporal_options(self.name(), &scalar_args, self.options)?;
+        let json_and_path = without_options(&args.args, self.options.len());
+        let result = invoke::<TimestampNanosecondArray>(&json_and_path, |json, path| {
+            jiter_json_get_temporal(json, path, |value| timestamp_nanos(&value, &options))
+        })?;
+        with_timezone(result, timestamp_tz(&options))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (options and a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// Timestamps are in the `tz` option's timezone, or UTC.
+fn timestamp_tz(options: &TemporalOptions) -> &str {
+    options.tz.as_deref().unwrap_or("UTC")
+}
+
+fn timestamp_type(options: &TemporalOptions) -> DataType {
+    DataType::Timestamp(TimeUnit::Nanosecond, Some(timestamp_tz(options).into()))
+}
+
+pub(crate) fn timestamp_nanos(value: &TemporalValue, options: &TemporalOptions) -> Option<i64> {
+    match value {
+        TemporalValue::Str(s) => parse_timestamp_nanos(s, options.format.as_deref(), options.tz.as_deref())
+            .or_else(|| number_to_nanos(value, options.unit)),
+        TemporalValue::Int(_) | TemporalValue::Float(_) => number_to_nanos(value, options.unit),
+    }
+}
+
+fn with_timezone(value: ColumnarValue, tz: &str) -> DataFusionResult<ColumnarValue> {
+    match value {
+        ColumnarValue::Array(array) => {
+            let array = array
+                .as_primitive::<TimestampNanosecondType>()
+                .clone()
+                .with_timezone(tz);
+            Ok(ColumnarValue::Array(Arc::new(array)))
+        }
+        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(value, _)) => Ok(ColumnarValue::Scalar(
+            ScalarValue::TimestampNanosecond(value, Some(tz.into())),
+        )),
+        ColumnarValue::Scalar(scalar) => internal_err!("unexpected json_get_timestamp result {scalar:?}"),
+    }
+}
+
+impl InvokeResult for TimestampNanosecondArray {
+    type Item = i64;
+
+    type Builder = TimestampNanosecondBuilder;
+
+    // Cheaper to return a timestamp array rather than dict-encoded timestamps
+    const ACCEPT_DICT_RETURN: bool = false;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        TimestampNanosecondBuilder::with_capacity(capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.append_option(value);
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        ScalarValue::TimestampNanosecond(value, None)
+    }
+}
@@ -0,0 +1,90 @@
+use datafusion::arrow::array::UInt64Array;
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::Result as DataFusionResult;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use jiter::{NumberInt, Peek};
+
+use crate::common::{get_err, invoke, jiter_json_find, return_type_check, GetError, JsonPath};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonGetUInt,
+    json_get_uint,
+    json_data path,
+    r#"Get an unsigned 64-bit integer value from a JSON string by its "path""#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonGetUInt {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonGetUInt {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_get_uint".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetUInt {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        return_type_check(arg_types, self.name(), DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        invoke::<UInt64Array>(&args.args, jiter_json_get_uint)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+pub(crate) fn jiter_json_get_uint(json_data: Option<&str>, path: &[JsonPath]) -> Result<u64, GetError> {
+    if let Some((mut jiter, peek)) = jiter_json_find(json_data, path) {
+        match peek {
+            Peek::String => {
+                let s = jiter.known_str()?;
+                s.parse::<u64>().map_err(|_| GetError)
+            }
+            Peek::Null | Peek::True | Peek::False | Peek::Infinity | Peek::NaN | Peek::Array | Peek::Object => {
+                get_err!()
+            }
+            _ => match jiter.known_int(peek)? {
+                NumberInt::Int(i) => u64::try_from(i).map_err(|_| GetError),
+                NumberInt::BigInt(b) => u64::try_from(&b).map_err(|_| GetError),
+            },
+        }
+    } else {
+        get_err!()
+    }
+}
@@ -0,0 +1,77 @@
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::arrow::array::BooleanArray;
+use datafusion::common::Result;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use jiter::Peek;
+
+use crate::common::{get_err, invoke, jiter_json_find, return_type_check, GetError, JsonPath};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonIsObject,
+    json_is_object,
+    json_data path,
+    r#"Is the value at "path" in the JSON string an object?"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonIsObject {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonIsObject {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_is_object".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonIsObject {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        return_type_check(arg_types, self.name(), DataType::Boolean).map(|_| DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        invoke::<BooleanArray>(&args.args, jiter_json_is_object)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+fn jiter_json_is_object(json_data: Option<&str>, path: &[JsonPath]) -> Result<bool, GetError> {
+    match jiter_json_find(json_data, path) {
+        Some((_, peek)) => Ok(peek == Peek::Object),
+        None => get_err!(),
+    }
+}
@@ -0,0 +1,67 @@
+use datafusion::arrow::datatypes::{DataType, FieldRef};
+use datafusion::common::Result as DataFusionResult;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::AggregateOrderSensitivity;
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+
+use crate::common_encode::json_text_field;
+use crate::common_macros::make_udaf_function;
+use crate::json_agg::{json_agg_return_type, json_agg_state_fields, JsonAggAccumulator};
+
+make_udaf_function!(
+    JsonObjectAgg,
+    json_object_agg,
+    key value,
+    r"Aggregate keys and values into a JSON object, in the order of the aggregate's `ORDER BY` if it has one"
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonObjectAgg {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonObjectAgg {
+    fn default() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+            aliases: ["json_object_agg".to_string()],
+        }
+    }
+}
+
+impl AggregateUDFImpl for JsonObjectAgg {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        json_agg_return_type(arg_types, self.name())
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> DataFusionResult<FieldRef> {
+        let arg_types: Vec<DataType> = arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        self.return_type(&arg_types)?;
+        Ok(json_text_field(self.name()))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> DataFusionResult<Box<dyn Accumulator>> {
+        Ok(Box::new(JsonAggAccumulator::try_new(&acc_args, self.name(), true)?))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> DataFusionResult<Vec<FieldRef>> {
+        Ok(json_agg_state_fields(&args))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn order_sensitivity(&self) -> AggregateOrderSensitivity {
+        AggregateOrderSensitivity::HardRequirement
+    }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::BooleanArray;
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::{exec_err, plan_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+
+use crate::common::{is_valid_json, jiter_json_find, json_values, return_type_check, unpack_dictionary};
+use crate::common_macros::make_udf_function;
+use crate::common_path::parse_postgres_json_path;
+
+make_udf_function!(
+    JsonPathExists,
+    json_path_exists,
+    json_data path,
+    r#"Does the Postgres style `JSONPath` "path" match any value in the JSON string? Used for the `@?` operator"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonPathExists {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonPathExists {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_path_exists".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonPathExists {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        jsonpath_return_type(arg_types, self.name())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        invoke_jsonpath(&args.args, self.name(), parse_postgres_json_path, |json, path| {
+            json.map(|json| jiter_json_find(Some(json), path).is_some())
+        })
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// The `JSONPath` functions take a JSON value and a single `JSONPath` string.
+pub(crate) fn jsonpath_return_type(arg_types: &[DataType], fn_name: &str) -> DataFusionResult<DataType> {
+    if arg_types.len() == 2 {
+        return_type_check(arg_types, fn_name, DataType::Boolean).map(|_| DataType::Boolean)
+    } else {
+        plan_err!("The '{fn_name}' function requires two arguments, a JSON value and a JSONPath.")
+    }
+}
+
+/// Test each JSON value with `test`, the `JSONPath` must be a literal so it's compiled with `parse` once for
+/// the whole batch. Invalid JSON is passed to `test` as `None`, like a null value, so the result is null rather than
+/// false.
+pub(crate) fn invoke_jsonpath<'a, T>(
+    args: &'a [ColumnarValue],
+    fn_name: &str,
+    parse: impl Fn(&'a str) -> DataFusionResult<T>,
+    test: impl Fn(Option<&str>, &T) -> Option<bool>,
+) -> DataFusionResult<ColumnarValue> {
+    let [json, ColumnarValue::Scalar(
+        ScalarValue::Utf8(Some(jsonpath))
+        | ScalarValue::Utf8View(Some(jsonpath))
+        | ScalarValue::LargeUtf8(Some(jsonpath)),
+    )] = args
+    else {
+        return exec_err!("The '{fn_name}' function requires a JSON value and a literal JSONPath string.");
+    };
+    let compiled = parse(jsonpath)?;
+    let test = |json: Option<&str>| test(json.filter(|json| is_valid_json(json)), &compiled);
+    match json {
+        ColumnarValue::Array(array) => {
+            let array = unpack_dictionary(array)?;
+            let result: BooleanArray = json_values(&array)?.into_iter().map(test).collect();
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+        ColumnarValue::Scalar(scalar) => {
+            let array = unpack_dictionary(&scalar.to_array()?)?;
+            let json = json_values(&array)?.first().copied().flatten();
+            Ok(ColumnarValue::Scalar(ScalarValue::Boolean(test(json))))
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::Result as DataFusionResult;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+
+use crate::common::json_predicate_matches;
+use crate::common_macros::make_udf_function;
+use crate::common_path::parse_postgres_json_predicate;
+use crate::json_path_exists::{invoke_jsonpath, jsonpath_return_type};
+
+make_udf_function!(
+    JsonPathMatch,
+    json_path_match,
+    json_data predicate,
+    r#"Is the Postgres style `JSONPath` "predicate" true for the JSON string? Used for the `@@` operator"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonPathMatch {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonPathMatch {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_path_match".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonPathMatch {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        jsonpath_return_type(arg_types, self.name())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        invoke_jsonpath(
+            &args.args,
+            self.name(),
+            parse_postgres_json_predicate,
+            json_predicate_matches,
+        )
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
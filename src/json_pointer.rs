@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{Array, AsArray, ListBuilder, StringBuilder};
+use datafusion::arrow::compute::kernels::cast;
+use datafusion::arrow::datatypes::{DataType, Field};
+use datafusion::common::{exec_err, Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+
+use crate::common_macros::make_udf_function;
+use crate::common_path::parse_json_pointer;
+
+make_udf_function!(
+    JsonPointer,
+    json_pointer,
+    pointer,
+    r#"Split an RFC 6901 JSON Pointer into a list of reference tokens, which can be used as a "path""#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonPointer {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonPointer {
+    fn default() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+            aliases: ["json_pointer".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonPointer {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        Ok(DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let [arg] = args.args.as_slice() else {
+            return exec_err!("json_pointer expects one argument");
+        };
+        let pointers = cast(&arg.to_array(1)?, &DataType::Utf8)?;
+        let pointers = pointers.as_string::<i32>();
+
+        // invalid pointers are null, like paths which don't match
+        let mut builder = ListBuilder::with_capacity(StringBuilder::new(), pointers.len());
+        for opt_pointer in pointers {
+            builder.append_option(
+                opt_pointer
+                    .and_then(parse_json_pointer)
+                    .map(|tokens| tokens.into_iter().map(Some)),
+            );
+        }
+        let tokens = builder.finish();
+
+        match arg {
+            ColumnarValue::Scalar(_) => Ok(ColumnarValue::Scalar(ScalarValue::List(Arc::new(tokens)))),
+            ColumnarValue::Array(_) => Ok(ColumnarValue::Array(Arc::new(tokens))),
+        }
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
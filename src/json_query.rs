@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{ArrayRef, ListArray, UnionArray};
+use datafusion::arrow::buffer::{NullBuffer, OffsetBuffer};
+use datafusion::arrow::datatypes::{DataType, Field};
+use datafusion::common::{Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+
+use crate::common::{get_err, invoke_query, jiter_json_find_all, return_type_check, GetError, InvokeResult, JsonPath};
+use crate::common_macros::make_udf_function;
+use crate::common_union::{JsonUnion, JsonUnionField};
+use crate::json_get::build_union;
+
+fn list_item_field() -> Field {
+    Field::new("item", JsonUnion::data_type(), true)
+}
+
+make_udf_function!(
+    JsonQuery,
+    json_query,
+    json_data path,
+    r#"Get every value matching a "path" from a JSON string, as an array of JSON unions"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonQuery {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonQuery {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_query".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonQuery {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        return_type_check(arg_types, self.name(), DataType::List(Arc::new(list_item_field())))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        invoke_query::<BuildListUnion>(&args.args, jiter_json_query)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// Struct used to build a `ListArray` of JSON unions from the result of `jiter_json_query`.
+#[derive(Debug)]
+struct BuildListUnion;
+
+impl InvokeResult for BuildListUnion {
+    type Item = Vec<JsonUnionField>;
+
+    type Builder = Vec<Option<Vec<JsonUnionField>>>;
+
+    const ACCEPT_DICT_RETURN: bool = true;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.push(value);
+    }
+
+    fn finish(builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(build_list(builder)?))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        let array = build_list(vec![value]).expect("a list of JSON unions is always valid");
+        ScalarValue::List(Arc::new(array))
+    }
+}
+
+fn build_list(rows: Vec<Option<Vec<JsonUnionField>>>) -> DataFusionResult<ListArray> {
+    let validity: NullBuffer = rows.iter().map(Option::is_some).collect();
+    let offsets = OffsetBuffer::from_lengths(rows.iter().map(|row| row.as_ref().map_or(0, Vec::len)));
+    let mut union = JsonUnion::new(rows.iter().flatten().map(Vec::len).sum());
+    for field in rows.into_iter().flatten().flatten() {
+        union.push(field);
+    }
+    let values: UnionArray = union.try_into()?;
+    Ok(ListArray::try_new(
+        Arc::new(list_item_field()),
+        offsets,
+        Arc::new(values),
+        Some(validity),
+    )?)
+}
+
+fn jiter_json_query(opt_json: Option<&str>, path: &[JsonPath]) -> Result<Vec<JsonUnionField>, GetError> {
+    if let Some(matches) = jiter_json_find_all(opt_json, path) {
+        matches
+            .into_iter()
+            .map(|(mut jiter, peek)| build_union(&mut jiter, peek))
+            .collect()
+    } else {
+        get_err!()
+    }
+}
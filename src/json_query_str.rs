@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{ArrayRef, ListBuilder, StringBuilder};
+use datafusion::arrow::datatypes::{DataType, Field};
+use datafusion::common::{Result as DataFusionResult, ScalarValue};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use jiter::Peek;
+
+use crate::common::{get_err, invoke_query, jiter_json_find_all, return_type_check, GetError, InvokeResult, JsonPath};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonQueryStr,
+    json_query_str,
+    json_data path,
+    r#"Get every string value matching a "path" from a JSON string, as an array of strings"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonQueryStr {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonQueryStr {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_query_str".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonQueryStr {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        return_type_check(
+            arg_types,
+            self.name(),
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+        )
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        invoke_query::<BuildStrList>(&args.args, jiter_json_query_str)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// Struct used to build a `ListArray` of strings from the result of `jiter_json_query_str`.
+#[derive(Debug)]
+struct BuildStrList;
+
+impl InvokeResult for BuildStrList {
+    type Item = Vec<Option<String>>;
+
+    type Builder = ListBuilder<StringBuilder>;
+
+    const ACCEPT_DICT_RETURN: bool = true;
+
+    fn builder(capacity: usize) -> Self::Builder {
+        let values_builder = StringBuilder::new();
+        ListBuilder::with_capacity(values_builder, capacity)
+    }
+
+    fn append_value(builder: &mut Self::Builder, value: Option<Self::Item>) {
+        builder.append_option(value);
+    }
+
+    fn finish(mut builder: Self::Builder) -> DataFusionResult<ArrayRef> {
+        Ok(Arc::new(builder.finish()))
+    }
+
+    fn scalar(value: Option<Self::Item>) -> ScalarValue {
+        let mut builder = Self::builder(1);
+        Self::append_value(&mut builder, value);
+        ScalarValue::List(Arc::new(builder.finish()))
+    }
+}
+
+/// Matches which aren't strings are included as nulls, so the positions of the matches are preserved.
+fn jiter_json_query_str(opt_json: Option<&str>, path: &[JsonPath]) -> Result<Vec<Option<String>>, GetError> {
+    if let Some(matches) = jiter_json_find_all(opt_json, path) {
+        matches
+            .into_iter()
+            .map(|(mut jiter, peek)| -> Result<Option<String>, GetError> {
+                match peek {
+                    Peek::String => Ok(Some(jiter.known_str()?.to_owned())),
+                    _ => Ok(None),
+                }
+            })
+            .collect()
+    } else {
+        get_err!()
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::{DataType, FieldRef};
+use datafusion::common::{plan_err, Result as DataFusionResult};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+use crate::common::return_type_check;
+use crate::common_edit::{edit_rows, Target};
+use crate::common_encode::json_text_field;
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonRemove,
+    json_remove,
+    json_data path,
+    r#"Remove the value at each "path" from a JSON document, every argument after the document is a separate path: a key, an index, a `JSONPath` or a list. Used for the `-` and `#-` operators"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonRemove {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonRemove {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_remove".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonRemove {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        if arg_types.len() < 2 {
+            return plan_err!("The '{}' function requires a JSON value and a path.", self.name());
+        }
+        return_type_check(arg_types, self.name(), DataType::Utf8)?;
+        Ok(DataType::Utf8)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        self.return_type(&arg_types)?;
+        Ok(json_text_field(self.name()))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        // each argument after the document is a path, removed in turn from the result of removing the previous one
+        let mut json = args.args[0].clone();
+        let mut json_field = Arc::clone(&args.arg_fields[0]);
+        for (path, path_field) in args.args.iter().zip(&args.arg_fields).skip(1) {
+            let path_args = ScalarFunctionArgs {
+                args: vec![json, path.clone()],
+                arg_fields: vec![json_field, Arc::clone(path_field)],
+                number_rows: args.number_rows,
+                return_field: Arc::clone(&args.return_field),
+                config_options: Arc::clone(&args.config_options),
+            };
+            json = remove_path(&path_args, self.name())?;
+            json_field = Arc::clone(&args.return_field);
+        }
+        Ok(json)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// Remove the value at the path in the second argument, the value and its key are removed along with one comma, the
+/// rest of the document is copied unchanged.
+fn remove_path(args: &ScalarFunctionArgs, fn_name: &str) -> DataFusionResult<ColumnarValue> {
+    edit_rows(args, 2, fn_name, |_, json, target, out| {
+        if let Target::Found { entry: Some(entry), .. } = target {
+            out.extend_from_slice(&json.as_bytes()[..entry.start]);
+            out.extend_from_slice(&json.as_bytes()[entry.end..]);
+        } else {
+            out.extend_from_slice(json.as_bytes());
+        }
+        Ok(true)
+    })
+}
@@ -0,0 +1,131 @@
+use datafusion::arrow::datatypes::{DataType, FieldRef};
+use datafusion::common::{exec_err, plan_err, Result as DataFusionResult};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+use crate::common::return_type_check;
+use crate::common_edit::{edit_rows, Target};
+use crate::common_encode::{check_encodable, json_text_field, ColumnEncoder};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonSet,
+    json_set,
+    json_data path value,
+    r#"Set the value at a "path" in a JSON document, adding it if it's missing"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonSet {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonSet {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_set".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonSet {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        set_return_type(arg_types, self.name())
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        self.return_type(&arg_types)?;
+        Ok(json_text_field(self.name()))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        invoke_set(&args, self.name(), SetMode::Set)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path and a value)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// Which of an existing value and a missing value is written by [`invoke_set`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SetMode {
+    /// Replace an existing value, or add a missing one
+    Set,
+    /// Only add a missing value
+    Insert,
+    /// Only replace an existing value
+    Replace,
+}
+
+/// The arguments are the JSON document, the path and the value, which can be of any type which can be encoded as
+/// JSON.
+pub(crate) fn set_return_type(arg_types: &[DataType], fn_name: &str) -> DataFusionResult<DataType> {
+    let Some((value, json_and_path)) = arg_types
+        .split_last()
+        .filter(|(_, json_and_path)| !json_and_path.is_empty())
+    else {
+        return plan_err!("The '{fn_name}' function requires a JSON value, a path and a value.");
+    };
+    return_type_check(json_and_path, fn_name, DataType::Utf8)?;
+    check_encodable(value, fn_name)?;
+    Ok(DataType::Utf8)
+}
+
+/// Write the value into the JSON document at the path of each row, the rest of the document is copied unchanged.
+///
+/// The document is unchanged if `mode` doesn't allow writing the value, or the parent of the value is missing or the
+/// wrong type.
+pub(crate) fn invoke_set(args: &ScalarFunctionArgs, fn_name: &str, mode: SetMode) -> DataFusionResult<ColumnarValue> {
+    let (Some(value_arg), Some(value_field)) = (args.args.last(), args.arg_fields.last()) else {
+        return exec_err!("The '{fn_name}' function requires a JSON value, a path and a value.");
+    };
+    let value = ColumnEncoder::try_new(value_arg, value_field)?;
+    edit_rows(args, args.args.len() - 1, fn_name, |row, json, target, out| {
+        match (target, mode) {
+            (Target::Found { value: range, .. }, SetMode::Set | SetMode::Replace) => {
+                out.extend_from_slice(&json.as_bytes()[..range.start]);
+                value.encode(row, out)?;
+                out.extend_from_slice(&json.as_bytes()[range.end..]);
+            }
+            (Target::Missing { at, prefix }, SetMode::Set | SetMode::Insert) => {
+                out.extend_from_slice(&json.as_bytes()[..at]);
+                out.extend_from_slice(&prefix);
+                value.encode(row, out)?;
+                out.extend_from_slice(&json.as_bytes()[at..]);
+            }
+            _ => out.extend_from_slice(json.as_bytes()),
+        }
+        Ok(true)
+    })
+}
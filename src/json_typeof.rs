@@ -0,0 +1,86 @@
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::arrow::array::StringArray;
+use datafusion::common::Result;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+use jiter::Peek;
+
+use crate::common::{get_err, invoke, jiter_json_find, return_type_check, GetError, JsonPath};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonTypeof,
+    json_typeof,
+    json_data path,
+    r#"Get the type of the value at "path" in the JSON string, 'object', 'array', 'string', 'number', 'boolean' or 'null'"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonTypeof {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonTypeof {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_typeof".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonTypeof {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        return_type_check(arg_types, self.name(), DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        invoke::<StringArray>(&args.args, jiter_json_typeof)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+fn jiter_json_typeof(json_data: Option<&str>, path: &[JsonPath]) -> Result<String, GetError> {
+    let Some((_, peek)) = jiter_json_find(json_data, path) else {
+        return get_err!();
+    };
+    let json_type = match peek {
+        Peek::Object => "object",
+        Peek::Array => "array",
+        Peek::String => "string",
+        Peek::True | Peek::False => "boolean",
+        Peek::Null => "null",
+        _ if peek.is_num() => "number",
+        _ => return get_err!(),
+    };
+    Ok(json_type.to_string())
+}
@@ -89,7 +89,7 @@ fn json_union_to_text_array(array: &ArrayRef) -> DataFusionResult<ArrayRef> {
 
     let mut builder = StringViewBuilder::with_capacity(encoder.len());
     // Scalar arms are JSON-encoded with serde_json (string escaping, float
-    // formatting, …); the array/object arms already hold raw JSON text and pass
+    // formatting, …); the array/object/bigint arms already hold raw JSON text and pass
     // through verbatim.
     let mut scratch: Vec<u8> = Vec::new();
     for idx in 0..encoder.len() {
@@ -103,7 +103,7 @@ fn json_union_to_text_array(array: &ArrayRef) -> DataFusionResult<ArrayRef> {
             JsonUnionValue::Int(i) => serde_json::to_writer(&mut scratch, &i),
             JsonUnionValue::Float(f) => serde_json::to_writer(&mut scratch, &f),
             JsonUnionValue::Str(s) => serde_json::to_writer(&mut scratch, s),
-            JsonUnionValue::Array(s) | JsonUnionValue::Object(s) => {
+            JsonUnionValue::Array(s) | JsonUnionValue::Object(s) | JsonUnionValue::BigInt(s) => {
                 builder.append_value(s);
                 continue;
             }
@@ -133,6 +133,7 @@ mod tests {
             Some(JsonUnionField::Str("foo\"bar\n\u{1}".to_string())),
             Some(JsonUnionField::Array("[1,2]".to_string())),
             Some(JsonUnionField::Object(r#"{"a":1}"#.to_string())),
+            Some(JsonUnionField::BigInt("-9223372036854775809".to_string())),
             None,
         ]);
         let array: ArrayRef = Arc::new(UnionArray::try_from(union).unwrap());
@@ -152,6 +153,7 @@ mod tests {
                 Some("\"foo\\\"bar\\n\\u0001\""), // Str: JSON-quoted + escaped (quote, newline, control char)
                 Some("[1,2]"),                    // Array (passthrough)
                 Some(r#"{"a":1}"#),               // Object (passthrough)
+                Some("-9223372036854775809"),     // BigInt (passthrough)
                 None,                             // None
             ]
         );
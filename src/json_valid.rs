@@ -0,0 +1,81 @@
+use datafusion::arrow::datatypes::DataType;
+use datafusion::common::arrow::array::BooleanArray;
+use datafusion::common::Result;
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility};
+
+use crate::common::{get_err, invoke, is_valid_json, jiter_json_find, return_type_check, GetError, JsonPath};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    JsonValid,
+    json_valid,
+    json_data path,
+    r#"Is the JSON string valid, and does the "path" exist if one is given?"#
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct JsonValid {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for JsonValid {
+    fn default() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+            aliases: ["json_valid".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonValid {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        return_type_check(arg_types, self.name(), DataType::Boolean).map(|_| DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        invoke::<BooleanArray>(&args.args, jiter_json_valid)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn placement(
+        &self,
+        args: &[datafusion::logical_expr::ExpressionPlacement],
+    ) -> datafusion::logical_expr::ExpressionPlacement {
+        // If the first argument is a column and the remaining arguments are literals (a path)
+        // then we can push this UDF down to the leaf nodes.
+        if args.len() >= 2
+            && matches!(args[0], datafusion::logical_expr::ExpressionPlacement::Column)
+            && args[1..]
+                .iter()
+                .all(|arg| matches!(arg, datafusion::logical_expr::ExpressionPlacement::Literal))
+        {
+            datafusion::logical_expr::ExpressionPlacement::MoveTowardsLeafNodes
+        } else {
+            datafusion::logical_expr::ExpressionPlacement::KeepInPlace
+        }
+    }
+}
+
+/// Unlike the other functions, which stop reading once they've found the value, the whole document is parsed
+/// so invalid JSON after the value, or trailing characters, make it invalid.
+fn jiter_json_valid(json_data: Option<&str>, path: &[JsonPath]) -> Result<bool, GetError> {
+    let Some(json) = json_data else {
+        return get_err!();
+    };
+    if !is_valid_json(json) {
+        return Ok(false);
+    }
+    Ok(path.is_empty() || jiter_json_find(json_data, path).is_some())
+}
@@ -3,61 +3,176 @@ use std::sync::Arc;
 
 use datafusion::common::Result;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 
 mod common;
+mod common_edit;
+mod common_encode;
 mod common_macros;
+mod common_path;
+mod common_schema;
+mod common_temporal;
 mod common_union;
+mod from_json;
+mod json_agg;
 mod json_as_text;
+mod json_build_array;
+mod json_build_object;
+mod json_contained_in;
 mod json_contains;
+mod json_contains_all;
+mod json_contains_any;
+mod json_contains_value;
 mod json_from_scalar;
 mod json_get;
 mod json_get_array;
 mod json_get_bool;
+mod json_get_date;
+mod json_get_decimal;
 mod json_get_float;
 mod json_get_int;
 mod json_get_json;
+mod json_get_map;
 mod json_get_str;
+mod json_get_struct;
+mod json_get_time;
+mod json_get_timestamp;
+mod json_get_uint;
+mod json_insert;
+mod json_is_array;
+mod json_is_object;
 mod json_length;
+mod json_object_agg;
 mod json_object_keys;
+mod json_path_exists;
+mod json_path_match;
+mod json_pointer;
+mod json_query;
+mod json_query_str;
+mod json_remove;
+mod json_replace;
+mod json_set;
+mod json_typeof;
 mod json_union_to_text;
+mod json_valid;
 mod rewrite;
+mod to_json;
 
 pub use common_union::{json_field_metadata, JsonUnionEncoder, JsonUnionValue, JSON_UNION_DATA_TYPE};
+pub use rewrite::JsonGetMergeRule;
 
 pub mod functions {
+    pub use crate::from_json::from_json;
+    pub use crate::json_agg::json_agg;
     pub use crate::json_as_text::json_as_text;
+    pub use crate::json_build_array::json_build_array;
+    pub use crate::json_build_object::json_build_object;
+    pub use crate::json_contained_in::json_contained_in;
     pub use crate::json_contains::json_contains;
+    pub use crate::json_contains_all::json_contains_all;
+    pub use crate::json_contains_any::json_contains_any;
+    pub use crate::json_contains_value::json_contains_value;
     pub use crate::json_from_scalar::json_from_scalar;
     pub use crate::json_get::json_get;
     pub use crate::json_get_array::json_get_array;
+    pub use crate::json_get_array::json_get_bool_array;
+    pub use crate::json_get_array::json_get_float_array;
+    pub use crate::json_get_array::json_get_int_array;
+    pub use crate::json_get_array::json_get_str_array;
     pub use crate::json_get_bool::json_get_bool;
+    pub use crate::json_get_date::json_get_date;
+    pub use crate::json_get_date::json_get_date_with;
+    pub use crate::json_get_decimal::json_get_decimal;
     pub use crate::json_get_float::json_get_float;
     pub use crate::json_get_int::json_get_int;
     pub use crate::json_get_json::json_get_json;
+    pub use crate::json_get_map::json_get_map;
+    pub use crate::json_get_map::json_get_map_with;
     pub use crate::json_get_str::json_get_str;
+    pub use crate::json_get_struct::json_get_struct;
+    pub use crate::json_get_time::json_get_time;
+    pub use crate::json_get_time::json_get_time_with;
+    pub use crate::json_get_timestamp::json_get_timestamp;
+    pub use crate::json_get_timestamp::json_get_timestamp_with;
+    pub use crate::json_get_uint::json_get_uint;
+    pub use crate::json_insert::json_insert;
+    pub use crate::json_is_array::json_is_array;
+    pub use crate::json_is_object::json_is_object;
     pub use crate::json_length::json_length;
+    pub use crate::json_object_agg::json_object_agg;
     pub use crate::json_object_keys::json_object_keys;
+    pub use crate::json_path_exists::json_path_exists;
+    pub use crate::json_path_match::json_path_match;
+    pub use crate::json_pointer::json_pointer;
+    pub use crate::json_query::json_query;
+    pub use crate::json_query_str::json_query_str;
+    pub use crate::json_remove::json_remove;
+    pub use crate::json_replace::json_replace;
+    pub use crate::json_set::json_set;
+    pub use crate::json_typeof::json_typeof;
     pub use crate::json_union_to_text::json_union_to_text;
+    pub use crate::json_valid::json_valid;
+    pub use crate::to_json::to_json;
 }
 
 pub mod udfs {
+    pub use crate::from_json::from_json_udf;
     pub use crate::json_as_text::json_as_text_udf;
+    pub use crate::json_build_array::json_build_array_udf;
+    pub use crate::json_build_object::json_build_object_udf;
+    pub use crate::json_contained_in::json_contained_in_udf;
     pub use crate::json_contains::json_contains_udf;
+    pub use crate::json_contains_all::json_contains_all_udf;
+    pub use crate::json_contains_any::json_contains_any_udf;
+    pub use crate::json_contains_value::json_contains_value_udf;
     pub use crate::json_from_scalar::json_from_scalar_udf;
     pub use crate::json_get::json_get_udf;
     pub use crate::json_get_array::json_get_array_udf;
+    pub use crate::json_get_array::json_get_bool_array_udf;
+    pub use crate::json_get_array::json_get_float_array_udf;
+    pub use crate::json_get_array::json_get_int_array_udf;
+    pub use crate::json_get_array::json_get_str_array_udf;
     pub use crate::json_get_bool::json_get_bool_udf;
+    pub use crate::json_get_date::json_get_date_udf;
+    pub use crate::json_get_date::json_get_date_with_udf;
+    pub use crate::json_get_decimal::json_get_decimal_udf;
     pub use crate::json_get_float::json_get_float_udf;
     pub use crate::json_get_int::json_get_int_udf;
     pub use crate::json_get_json::json_get_json_udf;
+    pub use crate::json_get_map::json_get_map_udf;
+    pub use crate::json_get_map::json_get_map_with_udf;
     pub use crate::json_get_str::json_get_str_udf;
+    pub use crate::json_get_struct::json_get_struct_udf;
+    pub use crate::json_get_time::json_get_time_udf;
+    pub use crate::json_get_time::json_get_time_with_udf;
+    pub use crate::json_get_timestamp::json_get_timestamp_udf;
+    pub use crate::json_get_timestamp::json_get_timestamp_with_udf;
+    pub use crate::json_get_uint::json_get_uint_udf;
+    pub use crate::json_insert::json_insert_udf;
+    pub use crate::json_is_array::json_is_array_udf;
+    pub use crate::json_is_object::json_is_object_udf;
     pub use crate::json_length::json_length_udf;
     pub use crate::json_object_keys::json_object_keys_udf;
+    pub use crate::json_path_exists::json_path_exists_udf;
+    pub use crate::json_path_match::json_path_match_udf;
+    pub use crate::json_pointer::json_pointer_udf;
+    pub use crate::json_query::json_query_udf;
+    pub use crate::json_query_str::json_query_str_udf;
+    pub use crate::json_remove::json_remove_udf;
+    pub use crate::json_replace::json_replace_udf;
+    pub use crate::json_set::json_set_udf;
+    pub use crate::json_typeof::json_typeof_udf;
     pub use crate::json_union_to_text::json_union_to_text_udf;
+    pub use crate::json_valid::json_valid_udf;
+    pub use crate::to_json::to_json_udf;
 }
 
-/// Register all JSON UDFs, and [`rewrite::JsonFunctionRewriter`] with the provided [`FunctionRegistry`].
+pub mod udafs {
+    pub use crate::json_agg::json_agg_udaf;
+    pub use crate::json_object_agg::json_object_agg_udaf;
+}
+
+/// Register all JSON UDFs and UDAFs, and [`rewrite::JsonFunctionRewriter`] with the provided [`FunctionRegistry`].
 ///
 /// # Arguments
 ///
@@ -71,16 +186,52 @@ pub fn register_all(registry: &mut dyn FunctionRegistry) -> Result<()> {
         json_get::json_get_udf(),
         json_get_bool::json_get_bool_udf(),
         json_get_float::json_get_float_udf(),
+        json_get_decimal::json_get_decimal_udf(),
+        json_get_timestamp::json_get_timestamp_udf(),
+        json_get_timestamp::json_get_timestamp_with_udf(),
+        json_get_date::json_get_date_udf(),
+        json_get_date::json_get_date_with_udf(),
+        json_get_time::json_get_time_udf(),
+        json_get_time::json_get_time_with_udf(),
         json_get_int::json_get_int_udf(),
+        json_get_uint::json_get_uint_udf(),
         json_get_json::json_get_json_udf(),
         json_get_array::json_get_array_udf(),
+        json_get_array::json_get_int_array_udf(),
+        json_get_array::json_get_float_array_udf(),
+        json_get_array::json_get_str_array_udf(),
+        json_get_array::json_get_bool_array_udf(),
+        json_get_map::json_get_map_udf(),
+        json_get_map::json_get_map_with_udf(),
         json_as_text::json_as_text_udf(),
         json_get_str::json_get_str_udf(),
+        json_get_struct::json_get_struct_udf(),
         json_contains::json_contains_udf(),
+        json_contains_any::json_contains_any_udf(),
+        json_contains_all::json_contains_all_udf(),
+        json_contains_value::json_contains_value_udf(),
+        json_contained_in::json_contained_in_udf(),
         json_length::json_length_udf(),
         json_object_keys::json_object_keys_udf(),
+        json_typeof::json_typeof_udf(),
+        json_is_object::json_is_object_udf(),
+        json_is_array::json_is_array_udf(),
+        json_valid::json_valid_udf(),
+        json_query::json_query_udf(),
+        json_query_str::json_query_str_udf(),
+        json_path_exists::json_path_exists_udf(),
+        json_path_match::json_path_match_udf(),
+        json_pointer::json_pointer_udf(),
         json_from_scalar::json_from_scalar_udf(),
+        json_build_object::json_build_object_udf(),
+        json_build_array::json_build_array_udf(),
+        to_json::to_json_udf(),
+        json_set::json_set_udf(),
+        json_insert::json_insert_udf(),
+        json_replace::json_replace_udf(),
+        json_remove::json_remove_udf(),
         json_union_to_text::json_union_to_text_udf(),
+        from_json::from_json_udf(),
     ];
     functions.into_iter().try_for_each(|udf| {
         let existing_udf = registry.register_udf(udf)?;
@@ -89,6 +240,15 @@ pub fn register_all(registry: &mut dyn FunctionRegistry) -> Result<()> {
         }
         Ok(()) as Result<()>
     })?;
+    let aggregate_functions: Vec<Arc<AggregateUDF>> =
+        vec![json_agg::json_agg_udaf(), json_object_agg::json_object_agg_udaf()];
+    aggregate_functions.into_iter().try_for_each(|udaf| {
+        let existing_udaf = registry.register_udaf(udaf)?;
+        if let Some(existing_udaf) = existing_udaf {
+            debug!("Overwrite existing UDAF: {}", existing_udaf.name());
+        }
+        Ok(()) as Result<()>
+    })?;
     registry.register_function_rewrite(Arc::new(rewrite::JsonFunctionRewriter))?;
     registry.register_expr_planner(Arc::new(rewrite::JsonExprPlanner))?;
 
@@ -1,18 +1,27 @@
+use std::collections::HashMap;
+use std::fmt::Write;
 use std::sync::Arc;
 
 use datafusion::arrow::datatypes::DataType;
 use datafusion::common::config::ConfigOptions;
-use datafusion::common::tree_node::Transformed;
+use datafusion::common::tree_node::{Transformed, TreeNode, TreeNodeRecursion};
 use datafusion::common::Column;
 use datafusion::common::DFSchema;
+use datafusion::common::ExprSchema;
 use datafusion::common::Result;
-use datafusion::logical_expr::expr::{Alias, Cast, Expr, ScalarFunction};
-use datafusion::logical_expr::expr_rewriter::FunctionRewrite;
+use datafusion::logical_expr::expr::{Alias, Cast, Expr, ScalarFunction, TryCast};
+use datafusion::logical_expr::expr_rewriter::{FunctionRewrite, NamePreserver};
 use datafusion::logical_expr::planner::{ExprPlanner, PlannerResult, RawBinaryExpr};
 use datafusion::logical_expr::sqlparser::ast::BinaryOperator;
-use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::{ExprSchemable, LogicalPlan, Projection, ScalarUDF};
+use datafusion::optimizer::{ApplyOrder, OptimizerConfig, OptimizerRule};
 use datafusion::scalar::ScalarValue;
 
+use crate::common::JsonPath;
+use crate::common_path::{is_json_path, parse_json_path};
+use crate::common_union::{is_json_field, is_json_union};
+use crate::json_get_struct::JsonStructField;
+
 #[derive(Debug)]
 pub(crate) struct JsonFunctionRewriter;
 
@@ -21,9 +30,9 @@ impl FunctionRewrite for JsonFunctionRewriter {
         "JsonFunctionRewriter"
     }
 
-    fn rewrite(&self, expr: Expr, _schema: &DFSchema, _config: &ConfigOptions) -> Result<Transformed<Expr>> {
+    fn rewrite(&self, expr: Expr, schema: &DFSchema, _config: &ConfigOptions) -> Result<Transformed<Expr>> {
         let transform = match &expr {
-            Expr::Cast(cast) => optimise_json_get_cast(cast),
+            Expr::Cast(cast) => optimise_json_get_cast(cast, schema),
             Expr::ScalarFunction(func) => unnest_json_calls(func),
             _ => None,
         };
@@ -33,24 +42,80 @@ impl FunctionRewrite for JsonFunctionRewriter {
 
 /// This replaces `get_json(foo, bar)::int` with `json_get_int(foo, bar)` so the JSON function can take care of
 /// extracting the right value type from JSON without the need to materialize the JSON union.
-fn optimise_json_get_cast(cast: &Cast) -> Option<Transformed<Expr>> {
+///
+/// The cast is kept where the function returns a different type, e.g. `::smallint` is rewritten to
+/// `try_cast(json_get_int(foo, bar) as smallint)`, so the expression's type doesn't change.
+fn optimise_json_get_cast(cast: &Cast, schema: &DFSchema) -> Option<Transformed<Expr>> {
     let scalar_func = extract_scalar_function(&cast.expr)?;
     if !is_json_get(scalar_func) {
         return None;
     }
+    let mut args = scalar_func.args.clone();
     let func = match cast.field.data_type() {
         DataType::Boolean => crate::json_get_bool::json_get_bool_udf(),
-        DataType::Float64 | DataType::Float32 | DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
-            crate::json_get_float::json_get_float_udf()
+        DataType::Float64 | DataType::Float32 | DataType::Float16 => crate::json_get_float::json_get_float_udf(),
+        DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+            args.push(Expr::Literal(ScalarValue::Int64(Some(i64::from(*precision))), None));
+            args.push(Expr::Literal(ScalarValue::Int64(Some(i64::from(*scale))), None));
+            crate::json_get_decimal::json_get_decimal_udf()
+        }
+        DataType::Timestamp(_, None) => crate::json_get_timestamp::json_get_timestamp_udf(),
+        DataType::Timestamp(_, Some(tz)) => {
+            // the format, unit and timezone options come before the path
+            let options = [
+                Expr::Literal(ScalarValue::Utf8(None), None),
+                Expr::Literal(ScalarValue::Utf8(None), None),
+                Expr::Literal(ScalarValue::Utf8(Some(tz.to_string())), None),
+            ];
+            args.splice(1..1, options);
+            crate::json_get_timestamp::json_get_timestamp_with_udf()
+        }
+        DataType::Date32 | DataType::Date64 => crate::json_get_date::json_get_date_udf(),
+        DataType::Time32(_) | DataType::Time64(_) => crate::json_get_time::json_get_time_udf(),
+        DataType::Int64 | DataType::Int32 | DataType::Int16 | DataType::Int8 => crate::json_get_int::json_get_int_udf(),
+        DataType::UInt64 | DataType::UInt32 | DataType::UInt16 | DataType::UInt8 => {
+            crate::json_get_uint::json_get_uint_udf()
         }
-        DataType::Int64 | DataType::Int32 => crate::json_get_int::json_get_int_udf(),
         DataType::Utf8 | DataType::Utf8View | DataType::LargeUtf8 => crate::json_get_str::json_get_str_udf(),
+        DataType::List(field) => typed_array_udf(field.data_type())?,
         _ => return None,
     };
-    Some(Transformed::yes(Expr::ScalarFunction(ScalarFunction {
-        func,
-        args: scalar_func.args.clone(),
-    })))
+    let expr = Expr::ScalarFunction(ScalarFunction { func, args });
+    Some(Transformed::yes(keep_cast(cast, expr, schema)))
+}
+
+/// The typed array function for a cast to a list of `element_type`.
+fn typed_array_udf(element_type: &DataType) -> Option<Arc<ScalarUDF>> {
+    match element_type {
+        DataType::Int64 | DataType::Int32 | DataType::Int16 | DataType::Int8 => {
+            Some(crate::json_get_array::json_get_int_array_udf())
+        }
+        DataType::Float64 | DataType::Float32 | DataType::Float16 => {
+            Some(crate::json_get_array::json_get_float_array_udf())
+        }
+        DataType::Utf8 | DataType::Utf8View | DataType::LargeUtf8 => {
+            Some(crate::json_get_array::json_get_str_array_udf())
+        }
+        DataType::Boolean => Some(crate::json_get_array::json_get_bool_array_udf()),
+        _ => None,
+    }
+}
+
+/// Keep the cast as a `TryCast` if `expr` doesn't have exactly the cast's type, e.g. a narrower integer or a function
+/// returning a dictionary for a dictionary column, so values which don't fit the narrower type are null like other
+/// values the function can't convert, rather than failing the query.
+fn keep_cast(cast: &Cast, expr: Expr, schema: &DFSchema) -> Expr {
+    if expr
+        .get_type(schema)
+        .is_ok_and(|data_type| &data_type == cast.field.data_type())
+    {
+        expr
+    } else {
+        Expr::TryCast(TryCast {
+            expr: Box::new(expr),
+            field: Arc::clone(&cast.field),
+        })
+    }
 }
 
 // Replace nested JSON functions e.g. `json_get(json_get(col, 'foo'), 'bar')` with `json_get(col, 'foo', 'bar')`
@@ -61,9 +126,24 @@ fn unnest_json_calls(func: &ScalarFunction) -> Option<Transformed<Expr>> {
             | "json_get_bool"
             | "json_get_float"
             | "json_get_int"
+            | "json_get_uint"
+            | "json_get_decimal"
+            | "json_get_timestamp"
+            | "json_get_date"
+            | "json_get_time"
             | "json_get_json"
+            | "json_get_int_array"
+            | "json_get_float_array"
+            | "json_get_str_array"
+            | "json_get_bool_array"
+            | "json_get_map"
+            | "json_typeof"
+            | "json_is_object"
+            | "json_is_array"
             | "json_get_str"
             | "json_as_text"
+            | "json_query"
+            | "json_query_str"
     ) {
         return None;
     }
@@ -77,6 +157,10 @@ fn unnest_json_calls(func: &ScalarFunction) -> Option<Transformed<Expr>> {
         return None;
     }
 
+    if !selects_one_value(&inner_func.args[1..]) {
+        return None;
+    }
+
     let mut args = inner_func.args.clone();
     args.extend(outer_args_iter.cloned());
     // See #23, unnest only when all lookup arguments are literals
@@ -90,6 +174,29 @@ fn unnest_json_calls(func: &ScalarFunction) -> Option<Transformed<Expr>> {
     }
 }
 
+/// Whether literal path arguments select a single value. Where `json_get`'s path selects several values, e.g. with
+/// `$[*]`, it returns the first or an array of them, so appending the outer path would apply it to different values.
+fn selects_one_value(path_args: &[Expr]) -> bool {
+    path_args.iter().all(|arg| match arg {
+        Expr::Literal(
+            ScalarValue::Utf8(Some(s)) | ScalarValue::Utf8View(Some(s)) | ScalarValue::LargeUtf8(Some(s)),
+            _,
+        ) if is_json_path(s) => parse_json_path(s).is_ok_and(|path| {
+            !path.iter().any(|element| {
+                matches!(
+                    element,
+                    JsonPath::Wildcard
+                        | JsonPath::RecursiveDescent
+                        | JsonPath::Slice(..)
+                        | JsonPath::Filter(_)
+                        | JsonPath::Condition(_)
+                )
+            })
+        }),
+        _ => true,
+    })
+}
+
 fn is_json_get(func: &ScalarFunction) -> bool {
     func.func.inner().is::<crate::json_get::JsonGet>()
 }
@@ -106,7 +213,17 @@ fn extract_scalar_function(expr: &Expr) -> Option<&ScalarFunction> {
 enum JsonOperator {
     Arrow,
     LongArrow,
+    HashArrow,
+    HashLongArrow,
     Question,
+    QuestionPipe,
+    QuestionAnd,
+    AtArrow,
+    ArrowAt,
+    AtQuestion,
+    AtAt,
+    Minus,
+    HashMinus,
 }
 
 impl TryFrom<&BinaryOperator> for JsonOperator {
@@ -116,7 +233,17 @@ impl TryFrom<&BinaryOperator> for JsonOperator {
         match op {
             BinaryOperator::Arrow => Ok(JsonOperator::Arrow),
             BinaryOperator::LongArrow => Ok(JsonOperator::LongArrow),
+            BinaryOperator::HashArrow => Ok(JsonOperator::HashArrow),
+            BinaryOperator::HashLongArrow => Ok(JsonOperator::HashLongArrow),
             BinaryOperator::Question => Ok(JsonOperator::Question),
+            BinaryOperator::QuestionPipe => Ok(JsonOperator::QuestionPipe),
+            BinaryOperator::QuestionAnd => Ok(JsonOperator::QuestionAnd),
+            BinaryOperator::AtArrow => Ok(JsonOperator::AtArrow),
+            BinaryOperator::ArrowAt => Ok(JsonOperator::ArrowAt),
+            BinaryOperator::AtQuestion => Ok(JsonOperator::AtQuestion),
+            BinaryOperator::AtAt => Ok(JsonOperator::AtAt),
+            BinaryOperator::Minus => Ok(JsonOperator::Minus),
+            BinaryOperator::HashMinus => Ok(JsonOperator::HashMinus),
             _ => Err(()),
         }
     }
@@ -125,9 +252,16 @@ impl TryFrom<&BinaryOperator> for JsonOperator {
 impl From<JsonOperator> for Arc<ScalarUDF> {
     fn from(op: JsonOperator) -> Arc<ScalarUDF> {
         match op {
-            JsonOperator::Arrow => crate::udfs::json_get_udf(),
-            JsonOperator::LongArrow => crate::udfs::json_as_text_udf(),
+            JsonOperator::Arrow | JsonOperator::HashArrow => crate::udfs::json_get_udf(),
+            JsonOperator::LongArrow | JsonOperator::HashLongArrow => crate::udfs::json_as_text_udf(),
             JsonOperator::Question => crate::udfs::json_contains_udf(),
+            JsonOperator::QuestionPipe => crate::udfs::json_contains_any_udf(),
+            JsonOperator::QuestionAnd => crate::udfs::json_contains_all_udf(),
+            JsonOperator::AtArrow => crate::udfs::json_contains_value_udf(),
+            JsonOperator::ArrowAt => crate::udfs::json_contained_in_udf(),
+            JsonOperator::AtQuestion => crate::udfs::json_path_exists_udf(),
+            JsonOperator::AtAt => crate::udfs::json_path_match_udf(),
+            JsonOperator::Minus | JsonOperator::HashMinus => crate::udfs::json_remove_udf(),
         }
     }
 }
@@ -137,7 +271,17 @@ impl std::fmt::Display for JsonOperator {
         match self {
             JsonOperator::Arrow => write!(f, "->"),
             JsonOperator::LongArrow => write!(f, "->>"),
+            JsonOperator::HashArrow => write!(f, "#>"),
+            JsonOperator::HashLongArrow => write!(f, "#>>"),
             JsonOperator::Question => write!(f, "?"),
+            JsonOperator::QuestionPipe => write!(f, "?|"),
+            JsonOperator::QuestionAnd => write!(f, "?&"),
+            JsonOperator::AtArrow => write!(f, "@>"),
+            JsonOperator::ArrowAt => write!(f, "<@"),
+            JsonOperator::AtQuestion => write!(f, "@?"),
+            JsonOperator::AtAt => write!(f, "@@"),
+            JsonOperator::Minus => write!(f, "-"),
+            JsonOperator::HashMinus => write!(f, "#-"),
         }
     }
 }
@@ -170,29 +314,320 @@ fn expr_to_sql_repr(expr: &Expr) -> String {
     }
 }
 
+/// Convert a postgres text array literal like `'{a,b,0}'` to a list literal, other expressions are unchanged.
+fn text_array_to_list(expr: Expr) -> Expr {
+    if let Expr::Literal(
+        ScalarValue::Utf8(Some(s)) | ScalarValue::Utf8View(Some(s)) | ScalarValue::LargeUtf8(Some(s)),
+        _,
+    ) = &expr
+    {
+        if let Some(items) = parse_text_array(s) {
+            let values = items.into_iter().map(ScalarValue::Utf8).collect::<Vec<_>>();
+            return Expr::Literal(
+                ScalarValue::List(ScalarValue::new_list_nullable(&values, &DataType::Utf8)),
+                None,
+            );
+        }
+    }
+    expr
+}
+
+/// Parse a postgres text array e.g. `{a,"b c",NULL}`, returns `None` if the string isn't a valid text array.
+fn parse_text_array(s: &str) -> Option<Vec<Option<String>>> {
+    let inner = s.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let mut items = Vec::new();
+    if inner.trim().is_empty() {
+        return Some(items);
+    }
+    let mut chars = inner.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut item = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => item.push(chars.next()?),
+                    c => item.push(c),
+                }
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            items.push(Some(item));
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                match c {
+                    '\\' => item.push(chars.next()?),
+                    '"' | '{' | '}' => return None,
+                    c => item.push(c),
+                }
+            }
+            let item = item.trim_end();
+            if item.is_empty() {
+                return None;
+            } else if item.eq_ignore_ascii_case("null") {
+                items.push(None);
+            } else {
+                items.push(Some(item.to_string()));
+            }
+        }
+        match chars.next() {
+            None => return Some(items),
+            Some(',') => {}
+            Some(_) => return None,
+        }
+    }
+}
+
 /// Implement a custom SQL planner to replace postgres JSON operators with custom UDFs
 #[derive(Debug, Default)]
 pub struct JsonExprPlanner;
 
 impl ExprPlanner for JsonExprPlanner {
-    fn plan_binary_op(&self, expr: RawBinaryExpr, _schema: &DFSchema) -> Result<PlannerResult<RawBinaryExpr>> {
+    fn plan_binary_op(&self, expr: RawBinaryExpr, schema: &DFSchema) -> Result<PlannerResult<RawBinaryExpr>> {
         let Ok(op) = JsonOperator::try_from(&expr.op) else {
             return Ok(PlannerResult::Original(expr));
         };
+        // `@>` and `<@` are also array containment operators, leave those to DataFusion
+        if matches!(op, JsonOperator::AtArrow | JsonOperator::ArrowAt)
+            && (is_list(&expr.left, schema) || is_list(&expr.right, schema))
+        {
+            return Ok(PlannerResult::Original(expr));
+        }
+        // `-` is also arithmetic, so it's only planned when it removes a key or index from JSON, and `#-` only when
+        // it removes a path from JSON
+        let is_json_operator = match op {
+            JsonOperator::Minus => is_json(&expr.left, schema) && is_key_or_index(&expr.right, schema),
+            JsonOperator::HashMinus => is_json(&expr.left, schema),
+            _ => true,
+        };
+        if !is_json_operator {
+            return Ok(PlannerResult::Original(expr));
+        }
 
         let left_repr = expr_to_sql_repr(&expr.left);
         let right_repr = expr_to_sql_repr(&expr.right);
 
         let alias_name = format!("{left_repr} {op} {right_repr}");
 
+        let right = match op {
+            // `#>`, `#>>` and `#-` take the whole path as a text array, e.g. `'{a,b,0}'`, and `?|` and `?&` a list of
+            // keys
+            JsonOperator::HashArrow
+            | JsonOperator::HashLongArrow
+            | JsonOperator::HashMinus
+            | JsonOperator::QuestionPipe
+            | JsonOperator::QuestionAnd => text_array_to_list(expr.right),
+            _ => expr.right,
+        };
+
         // we put the alias in so that default column titles are `foo -> bar` instead of `json_get(foo, bar)`
         Ok(PlannerResult::Planned(Expr::Alias(Alias::new(
             Expr::ScalarFunction(ScalarFunction {
                 func: op.into(),
-                args: vec![expr.left, expr.right],
+                args: vec![expr.left, right],
             }),
             None::<&str>,
             alias_name,
         ))))
     }
 }
+
+fn is_list(expr: &Expr, schema: &DFSchema) -> bool {
+    matches!(
+        expr.get_type(schema),
+        Ok(DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(..))
+    )
+}
+
+/// A field marked as JSON, e.g. the result of `json_get_json`, or a JSON union, possibly dictionary encoded. Plain
+/// strings aren't assumed to be JSON.
+fn is_json(expr: &Expr, schema: &DFSchema) -> bool {
+    let Ok((_, field)) = expr.to_field(schema) else {
+        return false;
+    };
+    is_json_field(&field)
+        || match field.data_type() {
+            DataType::Dictionary(_, value_type) => is_json_union(value_type),
+            data_type => is_json_union(data_type),
+        }
+}
+
+fn is_key_or_index(expr: &Expr, schema: &DFSchema) -> bool {
+    matches!(expr.get_type(schema), Ok(data_type) if is_str(&data_type) || data_type.is_integer())
+}
+
+fn is_str(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View)
+}
+
+/// Merges the `json_get*` calls in a projection which read the same column with literal paths into a single
+/// `json_get_struct` call, so each document is only parsed once. For example
+/// `select json_get_str(doc, 'a'), json_get_int(doc, 'b') from t` is planned as
+/// `select s['c0'], s['c1'] from (select json_get_struct(doc, '$[''a'']', 'str', '$[''b'']', 'int') as s from t)`.
+///
+/// This isn't added by [`crate::register_all`] since it's an optimizer rule, add it to a session with
+/// `SessionContext::add_optimizer_rule`.
+#[derive(Debug, Default)]
+pub struct JsonGetMergeRule;
+
+impl OptimizerRule for JsonGetMergeRule {
+    fn name(&self) -> &'static str {
+        "json_get_merge"
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        Some(ApplyOrder::TopDown)
+    }
+
+    fn rewrite(&self, plan: LogicalPlan, _config: &dyn OptimizerConfig) -> Result<Transformed<LogicalPlan>> {
+        match plan {
+            LogicalPlan::Projection(projection) => merge_json_gets(projection),
+            plan => Ok(Transformed::no(plan)),
+        }
+    }
+}
+
+fn merge_json_gets(projection: Projection) -> Result<Transformed<LogicalPlan>> {
+    let input_schema = Arc::clone(projection.input.schema());
+
+    // the distinct calls on each column, in the order they first appear
+    let mut groups: Vec<(Column, Vec<Expr>)> = Vec::new();
+    for expr in &projection.expr {
+        expr.apply(|e| {
+            let Some(column) = mergeable_json_get(e, &input_schema) else {
+                return Ok(TreeNodeRecursion::Continue);
+            };
+            match groups.iter_mut().find(|(c, _)| c == column) {
+                Some((_, calls)) if !calls.contains(e) => calls.push(e.clone()),
+                Some(_) => {}
+                None => groups.push((column.clone(), vec![e.clone()])),
+            }
+            Ok(TreeNodeRecursion::Jump)
+        })?;
+    }
+    groups.retain(|(_, calls)| calls.len() > 1);
+    if groups.is_empty() {
+        return Ok(Transformed::no(LogicalPlan::Projection(projection)));
+    }
+
+    // an inner projection passes through the input columns and adds a struct for each group, the calls
+    // are then replaced with the fields of that struct
+    let mut inner_exprs: Vec<Expr> = input_schema.columns().into_iter().map(Expr::Column).collect();
+    let mut replacements: HashMap<Expr, Expr> = HashMap::new();
+    let mut struct_index = 0;
+    for (column, calls) in groups {
+        let mut struct_name = format!("__json_struct_{struct_index}");
+        while input_schema.has_column_with_unqualified_name(&struct_name) {
+            struct_index += 1;
+            struct_name = format!("__json_struct_{struct_index}");
+        }
+        struct_index += 1;
+
+        let mut args = vec![Expr::Column(column)];
+        for call in calls {
+            let Expr::ScalarFunction(func) = &call else {
+                continue;
+            };
+            let (Some(path), Some(field_type)) =
+                (json_path_literal(&func.args[1..]), field_type_name(func.func.name()))
+            else {
+                continue;
+            };
+            let field_name = format!("c{}", (args.len() - 1) / 2);
+            args.push(Expr::Literal(ScalarValue::Utf8(Some(path)), None));
+            args.push(Expr::Literal(ScalarValue::Utf8(Some(field_type.to_string())), None));
+            let field = JsonStructField::call(Expr::Column(Column::from_name(struct_name.clone())), field_name);
+            replacements.insert(call, field);
+        }
+        inner_exprs.push(crate::udfs::json_get_struct_udf().call(args).alias(struct_name));
+    }
+    let inner = LogicalPlan::Projection(Projection::try_new(inner_exprs, projection.input)?);
+
+    let name_preserver = NamePreserver::new_for_projection();
+    let exprs = projection
+        .expr
+        .into_iter()
+        .map(|expr| {
+            let saved_name = name_preserver.save(&expr);
+            let expr = expr.transform_down(|e| match replacements.get(&e) {
+                Some(field) => Ok(Transformed::new(field.clone(), true, TreeNodeRecursion::Jump)),
+                None => Ok(Transformed::no(e)),
+            })?;
+            Ok(saved_name.restore(expr.data))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Transformed::yes(LogicalPlan::Projection(Projection::try_new(
+        exprs,
+        Arc::new(inner),
+    )?)))
+}
+
+/// The column a `json_get*` call reads, if the call can be merged into `json_get_struct`.
+fn mergeable_json_get<'e>(expr: &'e Expr, input_schema: &DFSchema) -> Option<&'e Column> {
+    let Expr::ScalarFunction(func) = expr else {
+        return None;
+    };
+    field_type_name(func.func.name())?;
+    let Some((Expr::Column(column), path)) = func.args.split_first() else {
+        return None;
+    };
+    // `json_get*` calls on dictionaries return dictionaries, which `json_get_struct` fields can't
+    let field = input_schema.field_from_column(column).ok()?;
+    if matches!(field.data_type(), DataType::Dictionary(_, _)) {
+        return None;
+    }
+    json_path_literal(path).map(|_| column)
+}
+
+/// The `json_get_struct` type name for a `json_get*` function.
+fn field_type_name(fn_name: &str) -> Option<&'static str> {
+    match fn_name {
+        "json_get" => Some("any"),
+        "json_get_str" => Some("str"),
+        "json_get_int" => Some("int"),
+        "json_get_float" => Some("float"),
+        "json_get_bool" => Some("bool"),
+        "json_get_json" => Some("json"),
+        "json_as_text" => Some("text"),
+        _ => None,
+    }
+}
+
+/// Combine literal path arguments into a single `JSONPath` string, `None` if they aren't all string or integer
+/// literals.
+fn json_path_literal(path: &[Expr]) -> Option<String> {
+    let mut json_path = "$".to_string();
+    for arg in path {
+        let Expr::Literal(scalar, _) = arg else {
+            return None;
+        };
+        match scalar {
+            ScalarValue::Utf8(Some(s)) | ScalarValue::Utf8View(Some(s)) | ScalarValue::LargeUtf8(Some(s)) => {
+                if is_json_path(s) {
+                    json_path.push_str(&s[1..]);
+                } else {
+                    json_path.push_str("['");
+                    for c in s.chars() {
+                        if matches!(c, '\'' | '\\') {
+                            json_path.push('\\');
+                        }
+                        json_path.push(c);
+                    }
+                    json_path.push_str("']");
+                }
+            }
+            // `JSONPath` indices are parsed as `i64`, so larger indices can't be merged
+            ScalarValue::UInt64(Some(index)) if i64::try_from(*index).is_err() => return None,
+            ScalarValue::Int8(Some(_))
+            | ScalarValue::Int16(Some(_))
+            | ScalarValue::Int32(Some(_))
+            | ScalarValue::Int64(Some(_))
+            | ScalarValue::UInt8(Some(_))
+            | ScalarValue::UInt16(Some(_))
+            | ScalarValue::UInt32(Some(_))
+            | ScalarValue::UInt64(Some(_)) => write!(json_path, "[{scalar}]").ok()?,
+            _ => return None,
+        }
+    }
+    Some(json_path)
+}
@@ -0,0 +1,71 @@
+use datafusion::arrow::datatypes::{DataType, FieldRef};
+use datafusion::common::{exec_err, Result as DataFusionResult};
+use datafusion::logical_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+use crate::common_encode::{check_encodable, encode_rows, json_text_field, ColumnEncoder};
+use crate::common_macros::make_udf_function;
+
+make_udf_function!(
+    ToJson,
+    to_json,
+    value,
+    r"Encode any value, including structs, lists and maps, as JSON text"
+);
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct ToJson {
+    signature: Signature,
+    aliases: [String; 1],
+}
+
+impl Default for ToJson {
+    fn default() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+            aliases: ["to_json".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ToJson {
+    fn name(&self) -> &str {
+        self.aliases[0].as_str()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        // The signature check ensures we only get one argument
+        check_encodable(&arg_types[0], self.name())?;
+        Ok(DataType::Utf8)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> DataFusionResult<FieldRef> {
+        let arg_types: Vec<DataType> = args.arg_fields.iter().map(|f| f.data_type().clone()).collect();
+        self.return_type(&arg_types)?;
+        Ok(json_text_field(self.name()))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DataFusionResult<ColumnarValue> {
+        let ([arg], [field]) = (args.args.as_slice(), args.arg_fields.as_slice()) else {
+            return exec_err!("The '{}' function requires one argument.", self.name());
+        };
+        let encoder = ColumnEncoder::try_new(arg, field)?;
+        // a null value is a null result rather than the JSON `null`
+        encode_rows(&args.args, args.number_rows, |row, out| {
+            if encoder.is_null(row) {
+                return Ok(false);
+            }
+            encoder.encode(row, out)?;
+            Ok(true)
+        })
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
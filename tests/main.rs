@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use datafusion::arrow::array::{Array, ArrayRef, DictionaryArray, RecordBatch};
-use datafusion::arrow::datatypes::{Field, Int64Type, Int8Type, Schema};
+use datafusion::arrow::array::{Array, ArrayRef, AsArray, DictionaryArray, RecordBatch};
+use datafusion::arrow::datatypes::{Field, Float64Type, Int32Type, Int64Type, Int8Type, Schema, TimeUnit};
+use datafusion::arrow::util::pretty::pretty_format_batches;
 use datafusion::arrow::{array::StringDictionaryBuilder, datatypes::DataType};
 use datafusion::assert_batches_eq;
 use datafusion::common::ScalarValue;
@@ -13,7 +14,7 @@ use datafusion_functions_json::json_field_metadata;
 use datafusion_functions_json::udfs::json_get_str_udf;
 use utils::{create_context, display_val, logical_plan, run_query, run_query_params};
 
-use crate::utils::{for_all_json_datatypes, run_query_datatype};
+use crate::utils::{for_all_json_datatypes, run_query_datatype, run_query_merged};
 
 mod utils;
 
@@ -192,6 +193,265 @@ fn assert_json_field_metadata(metadata: &HashMap<String, String>) {
     assert_eq!(metadata.get("ARROW:extension:metadata").map(String::as_str), Some("{}"));
 }
 
+#[tokio::test]
+async fn test_json_get_typed_arrays() {
+    let json = r#"'{"a": [1, "2", 3.5, null, true, 4, "x", false]}'"#;
+    let cases = [
+        ("json_get_int_array", DataType::Int64, "[1, , , , , 4, , ]"),
+        ("json_get_float_array", DataType::Float64, "[1.0, , 3.5, , , 4.0, , ]"),
+        ("json_get_str_array", DataType::Utf8, "[, 2, , , , , x, ]"),
+        ("json_get_bool_array", DataType::Boolean, "[, , , , true, , , false]"),
+    ];
+    for (func, element_type, expected) in cases {
+        let batches = run_query(&format!("select {func}({json}, 'a')")).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::new_list(element_type, true), expected.to_string()),
+            "{func}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_json_get_typed_array_not_array() {
+    let batches = run_query(r#"select json_get_int_array('{"a": 1}', 'a')"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::new_list(DataType::Int64, true), String::new())
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_typed_array_column() {
+    let sql = "select name, json_get_int_array(json_data, 'foo') v, json_get_str_array(json_data) s from test";
+    let expected = [
+        "+------------------+-----+-------+",
+        "| name             | v   | s     |",
+        "+------------------+-----+-------+",
+        "| object_foo       |     |       |",
+        "| object_foo_array | [1] |       |",
+        "| object_foo_obj   |     |       |",
+        "| object_foo_null  |     |       |",
+        "| object_bar       |     |       |",
+        "| list_foo         |     | [foo] |",
+        "| invalid_json     |     |       |",
+        "+------------------+-----+-------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_get_cast_typed_array() {
+    let json = r#"'{"i": [1, 2, "x"], "f": [1.5, 2], "s": ["a", 1], "b": [true, 0]}'"#;
+    let cases = [
+        ("json_get({json}, 'i')::bigint[]", DataType::Int64, "[1, 2, ]"),
+        ("json_get({json}, 'i')::int[]", DataType::Int32, "[1, 2, ]"),
+        ("json_get({json}, 'f')::double[]", DataType::Float64, "[1.5, 2.0]"),
+        ("json_get({json}, 's')::text[]", DataType::Utf8View, "[a, ]"),
+        ("json_get({json}, 'b')::boolean[]", DataType::Boolean, "[true, ]"),
+    ];
+    for (expr, element_type, expected) in cases {
+        let sql = format!("select {}", expr.replace("{json}", json));
+        let batches = run_query(&sql).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::new_list(element_type, true), expected.to_string()),
+            "{expr}"
+        );
+    }
+
+    // typed array functions return a dictionary for a dictionary column, so the cast to the list is kept
+    let sql = r#"select json_get(arrow_cast(j, 'Dictionary(Int32, Utf8)'), 'a')::bigint[]
+        from (values ('{"a": [1, 2]}')) as t(j)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::new_list(DataType::Int64, true), "[1, 2]".to_string())
+    );
+
+    let lines = logical_plan(r"explain select (json_data->'foo')::bigint[] from test").await;
+    assert_eq!(
+        lines[0],
+        "Projection: json_get_int_array(test.json_data, Utf8(\"foo\")) AS json_data -> 'foo'"
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_map() {
+    let sql = r#"select json_get_map('{"env": "prod", "region": "eu", "n": 1}')"#;
+    let batches = run_query(sql).await.unwrap();
+    let (value_type, value_repr) = display_val(batches).await;
+    assert!(matches!(value_type, DataType::Map(_, false)));
+    assert_eq!(value_repr, "{env: prod, region: eu, n: }");
+
+    let sql = r#"select json_get_map_with('{"tags": {"a": "x", "b": [1, 2]}}', 'json', 'tags')"#;
+    let batches = run_query(sql).await.unwrap();
+    let (value_type, value_repr) = display_val(batches).await;
+    let DataType::Map(entries, _) = value_type else {
+        panic!("expected Map, got {value_type:?}");
+    };
+    let DataType::Struct(fields) = entries.data_type() else {
+        panic!("expected Struct entries, got {:?}", entries.data_type());
+    };
+    assert_json_field_metadata(fields[1].metadata());
+    assert_eq!(value_repr, r#"{a: "x", b: [1, 2]}"#);
+
+    let sql = r#"select json_get_map_with('{"tags": {"a": "x", "b": [1, 2]}}', null, 'tags')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "{a: x, b: }");
+
+    let sql = r#"select map_extract(json_get_map('{"env": "prod"}'), 'env')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[prod]");
+
+    // keys which look like `name=value` options are still path keys
+    let sql = r#"select json_get_map('{"values=json": {"a": "x"}}', 'values=json')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "{a: x}");
+}
+
+#[tokio::test]
+async fn test_json_get_map_column() {
+    let sql = "select name, json_get_map(json_data) m, json_get_map(json_data, 'foo') foo from test";
+    let expected = [
+        "+------------------+------------+-----+",
+        "| name             | m          | foo |",
+        "+------------------+------------+-----+",
+        "| object_foo       | {foo: abc} |     |",
+        "| object_foo_array | {foo: }    |     |",
+        "| object_foo_obj   | {foo: }    | {}  |",
+        "| object_foo_null  | {foo: }    |     |",
+        "| object_bar       | {bar: }    |     |",
+        "| list_foo         |            |     |",
+        "| invalid_json     |            |     |",
+        "+------------------+------------+-----+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_get_map_invalid_values() {
+    let err = run_query("select json_get_map_with(json_data, 'text') from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: Unknown values 'text' for 'json_get_map_with', expected 'str' or 'json'."
+    );
+
+    let err = run_query("select json_get_map_with(json_data, name) from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: The 'values' option of 'json_get_map_with' must be a string literal or null."
+    );
+}
+
+#[tokio::test]
+async fn test_json_typeof() {
+    let sql = "select name, json_typeof(json_data) t, json_typeof(json_data, 'foo') foo from test";
+    let expected = [
+        "+------------------+--------+--------+",
+        "| name             | t      | foo    |",
+        "+------------------+--------+--------+",
+        "| object_foo       | object | string |",
+        "| object_foo_array | object | array  |",
+        "| object_foo_obj   | object | object |",
+        "| object_foo_null  | object | null   |",
+        "| object_bar       | object |        |",
+        "| list_foo         | array  |        |",
+        "| invalid_json     |        |        |",
+        "+------------------+--------+--------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+
+    let json = r#"'{"n": -1.5, "b": false}'"#;
+    for (key, expected) in [("n", "number"), ("b", "boolean")] {
+        let batches = run_query(&format!("select json_typeof({json}, '{key}')"))
+            .await
+            .unwrap();
+        assert_eq!(display_val(batches).await, (DataType::Utf8, expected.to_string()));
+    }
+}
+
+#[tokio::test]
+async fn test_json_is_object_array() {
+    let sql = "select name, json_is_object(json_data, 'foo') o, json_is_array(json_data, 'foo') a from test";
+    let expected = [
+        "+------------------+-------+-------+",
+        "| name             | o     | a     |",
+        "+------------------+-------+-------+",
+        "| object_foo       | false | false |",
+        "| object_foo_array | false | true  |",
+        "| object_foo_obj   | true  | false |",
+        "| object_foo_null  | false | false |",
+        "| object_bar       |       |       |",
+        "| list_foo         |       |       |",
+        "| invalid_json     |       |       |",
+        "+------------------+-------+-------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_valid() {
+    let sql = "select name, json_valid(json_data) v, json_valid(json_data, 'foo') foo from test";
+    let expected = [
+        "+------------------+-------+-------+",
+        "| name             | v     | foo   |",
+        "+------------------+-------+-------+",
+        "| object_foo       | true  | true  |",
+        "| object_foo_array | true  | true  |",
+        "| object_foo_obj   | true  | true  |",
+        "| object_foo_null  | true  | true  |",
+        "| object_bar       | true  | false |",
+        "| list_foo         | true  | false |",
+        "| invalid_json     | false | false |",
+        "+------------------+-------+-------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+
+    let cases = [
+        (r#"'{"a": 1}  '"#, "true"),
+        (r#"'{"a": 1} x'"#, "false"),
+        (r#"'{"a": 1}{}'"#, "false"),
+        (r#"'{"a": 1, "b": tru}', 'a'"#, "false"),
+        (r#"'{"a": [1, }'"#, "false"),
+        ("null::text", ""),
+    ];
+    for (args, expected) in cases {
+        let batches = run_query(&format!("select json_valid({args})")).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Boolean, expected.to_string()),
+            "{args}"
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_json_get_equals() {
     let e = run_query(r"select name, json_get(json_data, 'foo')='abc' from test")
@@ -249,42 +509,507 @@ async fn test_json_get_str() {
 }
 
 #[tokio::test]
-async fn test_json_get_str_equals() {
-    let sql = "select name, json_get_str(json_data, 'foo')='abc' from test";
+async fn test_json_get_str_equals() {
+    let sql = "select name, json_get_str(json_data, 'foo')='abc' from test";
+    let batches = run_query(sql).await.unwrap();
+
+    let expected = [
+        "+------------------+--------------------------------------------------------+",
+        "| name             | json_get_str(test.json_data,Utf8(\"foo\")) = Utf8(\"abc\") |",
+        "+------------------+--------------------------------------------------------+",
+        "| object_foo       | true                                                   |",
+        "| object_foo_array |                                                        |",
+        "| object_foo_obj   |                                                        |",
+        "| object_foo_null  |                                                        |",
+        "| object_bar       |                                                        |",
+        "| list_foo         |                                                        |",
+        "| invalid_json     |                                                        |",
+        "+------------------+--------------------------------------------------------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_json_get_str_int() {
+    let sql = r#"select json_get_str('["a", "b", "c"]', 1)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "b".to_string()));
+
+    let sql = r#"select json_get_str('["a", "b", "c"]', 3)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, String::new()));
+}
+
+#[tokio::test]
+async fn test_json_get_str_path() {
+    let sql = r#"select json_get_str('{"a": {"aa": "x", "ab: "y"}, "b": []}', 'a', 'aa')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "x".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_str_json_path() {
+    let sql = r#"select json_get_str('{"a": {"b": ["x", "y", "z"]}}', '$.a.b[2]')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "z".to_string()));
+
+    // JSONPath elements are spliced in alongside plain path arguments
+    let sql = r#"select json_get_str('{"a": {"b": ["x", "y", "z"]}}', '$.a', 'b', 0)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "x".to_string()));
+
+    // bracket notation allows keys which aren't valid in dot notation
+    let sql = r#"select json_get_int('{"a.b": {"c d": 1}}', '$["a.b"][''c d'']')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "1".to_string()));
+
+    // `$` alone is the root value
+    let batches = run_query("select json_get_int('42', '$')").await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "42".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_json_path_column() {
+    let sql =
+        "select name, json_get_str(json_data, '$.foo') as foo from test where name in ('object_foo', 'object_bar')";
+    let expected = [
+        "+------------+-----+",
+        "| name       | foo |",
+        "+------------+-----+",
+        "| object_foo | abc |",
+        "| object_bar |     |",
+        "+------------+-----+",
+    ];
+
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_get_dollar_key() {
+    // keys which only start with `$` are still looked up literally
+    let sql = r#"select json_get_str('{"$oid": "abc"}', '$oid')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "abc".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_invalid_json_path() {
+    let err = run_query("select json_get_str('{}', '$.')").await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Invalid JSONPath '$.' at position 2: expected a member name after '.'"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_json_query_recursive_descent() {
+    let sql = r#"select json_query('{"a": {"price": 1, "b": [{"price": 2}]}, "price": 3}', '$..price')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[{int=3}, {int=1}, {int=2}]");
+}
+
+#[tokio::test]
+async fn test_json_query_wildcards() {
+    // like jq, `[*]` matches the members of an object as well as the elements of an array
+    let sql = r#"select json_query('{"a": [1, "x"], "b": {"c": null}}', '$.*[*]')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[{int=1}, {str=x}, {null=}]");
+
+    let sql = r#"select json_query('{"a": [1, "x"], "b": {"c": null}}', '$.*.*')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[{int=1}, {str=x}, {null=}]");
+}
+
+#[tokio::test]
+async fn test_json_query_str() {
+    let sql = r#"select json_query_str('{"items": [{"sku": "a"}, {"sku": 2}, {"sku": "c"}, {}]}', '$.items[*].sku')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            "[a, , c]".to_string()
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_json_query_no_matches() {
+    let expected = [
+        "+-------+---------+",
+        "| empty | invalid |",
+        "+-------+---------+",
+        "| []    |         |",
+        "+-------+---------+",
+    ];
+    let sql = "select json_query('[]', '$[*]') as empty, json_query('is not json', '$..a') as invalid";
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_json_query_column() {
+    let expected = [
+        "+------------------+---------------+",
+        "| name             | v             |",
+        "+------------------+---------------+",
+        "| object_foo       | [{str=abc}]   |",
+        "| object_foo_array | [{array=[1]}] |",
+        "| object_foo_obj   | [{object={}}] |",
+        "| object_foo_null  | [{null=}]     |",
+        "| object_bar       | [{bool=true}] |",
+        "| list_foo         | [{str=foo}]   |",
+        "| invalid_json     |               |",
+        "+------------------+---------------+",
+    ];
+
+    let sql = "select name, json_query(json_data, '$.*') as v from test";
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_get_first_match() {
+    let sql = r#"select json_get_int('{"a": {"x": 1}, "b": 2, "c": {"b": 3}}', '$..b')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "2".to_string()));
+
+    let sql = r#"select json_get_str('[{"b": 1}, {"a": "x"}, {"a": "y"}]', '$[*].a')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "x".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_negative_index() {
+    let sql = "select json_get('[1, 2, 3]', -1)";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "{int=3}");
+
+    let sql = "select json_get_int('[1, 2, 3]', -3)";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "1".to_string()));
+
+    let sql = "select json_get_int('[1, 2, 3]', -4)";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, String::new()));
+
+    let sql = r#"select json_get_str('{"a": ["x", "y"]}', '$.a[-1]')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "y".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_slice() {
+    let sql = "select json_get_json('[1, 2, 3, 4]', '$[1:3]')";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "[2,3]".to_string()));
+
+    let sql = "select json_get('[1, 2, 3, 4]', '$[-2:]')";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "{array=[3,4]}");
+
+    let sql = "select json_length('[1, 2, 3, 4]', '$[:10]')";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::UInt64, "4".to_string()));
+
+    let sql = "select json_get_json('[1, 2, 3, 4]', '$[3:1]')";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "[]".to_string()));
+
+    // the rest of the path applies to the sliced array
+    let sql = "select json_get_int('[[1, 2], [3, 4], [5]]', '$[1:][0][1]')";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "4".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_query_slice() {
+    let sql = r#"select json_query('[{"a": 1}, {"a": 2}, {"a": 3}]', '$[-2:].a')"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[{int=2}, {int=3}]");
+}
+
+#[tokio::test]
+async fn test_json_get_filter() {
+    let json = r#"{"attributes": [{"key": "service", "value": "api"}, {"key": "user_id", "value": "u42"}]}"#;
+
+    let sql = format!("select json_get_str('{json}', '$.attributes[?(@.key == ''user_id'')].value')");
+    let batches = run_query(&sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "u42".to_string()));
+
+    let sql = format!("select json_get('{json}', '$.attributes[?(@.key == ''missing'')].value')");
+    let batches = run_query(&sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "{null=}");
+
+    let sql = format!("select json_contains('{json}', '$.attributes[?(@.key == ''service'' && @.value)]')");
+    let batches = run_query(&sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Boolean, "true".to_string()));
+
+    let sql = format!("select json_contains('{json}', '$.attributes[?(@.key != ''service'' && !@.value)]')");
+    let batches = run_query(&sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Boolean, "false".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_query_filter() {
+    let json = r#"[{"n": 1, "tag": "a"}, {"n": 5}, {"n": "5"}, {"n": 10, "tag": null}, {"n": [5]}]"#;
+
+    let sql = format!("select json_query('{json}', '$[?(@.n >= 5 || @.tag == ''a'')].n')");
+    let batches = run_query(&sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[{int=1}, {int=5}, {int=10}]");
+
+    let sql = format!("select json_query('{json}', '$[?@.tag == null].n')");
+    let batches = run_query(&sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[{int=10}]");
+
+    let sql = format!("select json_query('{json}', '$[?(@.n == ''5'')].n')");
+    let batches = run_query(&sql).await.unwrap();
+    assert_eq!(display_val(batches).await.1, "[{str=5}]");
+}
+
+#[tokio::test]
+async fn test_json_pointer() {
+    let sql = r"select json_pointer('/a/b~1c/0')";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            "[a, b/c, 0]".to_string()
+        )
+    );
+
+    let sql = r#"select json_get_int('{"a": {"b/c": [1, 2]}}', json_pointer('/a/b~1c/1'))"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "2".to_string()));
+
+    // "0" is a member name on objects
+    let sql = r#"select json_get_str('{"0": "zero"}', json_pointer('/0'))"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "zero".to_string()));
+
+    let sql = r#"select json_get_json('{"a": 1}', json_pointer(''))"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, r#"{"a": 1}"#.to_string()));
+}
+
+#[tokio::test]
+async fn test_json_pointer_column() {
+    let expected = [
+        "+------------+-----+",
+        "| ptr        | v   |",
+        "+------------+-----+",
+        "| /a/b~1c/1  | y   |",
+        "| /a/b~1c/2  |     |",
+        "| /a/b~1c/01 |     |",
+        "| /z         | top |",
+        "| z          |     |",
+        "+------------+-----+",
+    ];
+    let sql = r#"
+        select ptr, json_get_str('{"a": {"b/c": ["x", "y"]}, "z": "top"}', json_pointer(ptr)) as v
+        from (values ('/a/b~1c/1'), ('/a/b~1c/2'), ('/a/b~1c/01'), ('/z'), ('z')) as t(ptr)
+    "#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(expected, &batches);
+
+    let sql = r#"
+        select json_get_str(doc, json_pointer(ptr)) as v
+        from (values ('{"a": ["x", "y"]}', '/a/0'), ('{"a": {"b": "z"}}', '/a/b')) as t(doc, ptr)
+    "#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(["+---+", "| v |", "+---+", "| x |", "| z |", "+---+"], &batches);
+}
+
+#[tokio::test]
+async fn test_json_get_struct() {
+    let sql = "select name, get_field(s, 'c0') a, get_field(s, 'c1') b, get_field(s, 'c2') c from \
+               (select name, json_get_struct(json_data, 'foo', 'str', '$.foo[0]', 'int', 'bar', 'bool') s from test)";
+    let expected = [
+        "+------------------+-----+---+------+",
+        "| name             | a   | b | c    |",
+        "+------------------+-----+---+------+",
+        "| object_foo       | abc |   |      |",
+        "| object_foo_array |     | 1 |      |",
+        "| object_foo_obj   |     |   |      |",
+        "| object_foo_null  |     |   |      |",
+        "| object_bar       |     |   | true |",
+        "| list_foo         |     |   |      |",
+        "| invalid_json     |     |   |      |",
+        "+------------------+-----+---+------+",
+    ];
+
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_json_get_struct_scalar() {
+    let sql = "select get_field(json_get_struct('{\"a\": {\"b\": 1}, \"c\": [2]}', '$.a.b', 'int', 'c', 'json'), 'c1')";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "[2]".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_struct_bad_type() {
+    let sql = "select json_get_struct(json_data, 'foo', 'decimal') from test";
+    let err = run_query(sql).await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: Unknown type 'decimal' for 'json_get_struct' at position 3, expected one of 'any', \
+         'str', 'int', 'float', 'bool', 'json' or 'text'."
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_merge_rule() {
+    let sql = "select name, json_data->>'foo' a, json_get_str(json_data, 'foo') b, json_data->'foo'->0 c, \
+               json_get_bool(json_data, 'bar') d from test";
+
+    let batches = run_query_merged(&format!("explain {sql}"), &DataType::Utf8)
+        .await
+        .unwrap();
+    let plan = pretty_format_batches(&batches).unwrap().to_string();
+    let physical_plan = plan
+        .lines()
+        .skip_while(|line| !line.contains("physical_plan"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(physical_plan.matches("json_get_struct(").count(), 1, "{plan}");
+
+    for_all_json_datatypes(async |dt| {
+        let merged = run_query_merged(sql, dt).await.unwrap();
+        let expected = run_query_datatype(sql, dt).await.unwrap();
+        assert_eq!(
+            pretty_format_batches(&merged).unwrap().to_string(),
+            pretty_format_batches(&expected).unwrap().to_string()
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_get_merge_rule_large_index() {
+    // an index beyond the range of i64 can't be written in a JSONPath, so it isn't merged
+    let sql = "select json_get_str(json_data, 'foo') a, json_get(json_data, 18446744073709551615) b, \
+               json_get_bool(json_data, 'bar') c from test";
+
+    let merged = run_query_merged(sql, &DataType::Utf8).await.unwrap();
+    let expected = run_query_datatype(sql, &DataType::Utf8).await.unwrap();
+    assert_eq!(
+        pretty_format_batches(&merged).unwrap().to_string(),
+        pretty_format_batches(&expected).unwrap().to_string()
+    );
+}
+
+#[tokio::test]
+async fn test_from_json() {
+    let sql = "select name, get_field(s, 'foo') foo, get_field(s, 'bar') bar from \
+               (select name, from_json(json_data, 'foo STRING, bar BOOLEAN') s from test)";
+    let expected = [
+        "+------------------+-----+------+",
+        "| name             | foo | bar  |",
+        "+------------------+-----+------+",
+        "| object_foo       | abc |      |",
+        "| object_foo_array | [1] |      |",
+        "| object_foo_obj   | {}  |      |",
+        "| object_foo_null  |     |      |",
+        "| object_bar       |     | true |",
+        "| list_foo         |     |      |",
+        "| invalid_json     |     |      |",
+        "+------------------+-----+------+",
+    ];
+
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_from_json_nested() {
+    let sql = r#"select from_json(
+        '{"a": 1, "b": {"c": "x", "c": "y"}, "d": [1.5, 2, "no"], "m": {"k": 1, "l": true}}',
+        'a INT, b STRUCT<c: STRING>, d ARRAY<DOUBLE>, m MAP<STRING, INT>'
+    ) s"#;
     let batches = run_query(sql).await.unwrap();
+    let s = batches[0].column(0).as_struct();
+    assert_eq!(s.column_by_name("a").unwrap().as_primitive::<Int32Type>().value(0), 1);
+
+    let b = s.column_by_name("b").unwrap().as_struct();
+    assert_eq!(b.column(0).as_string::<i32>().value(0), "x");
+
+    let d = s.column_by_name("d").unwrap().as_list::<i32>().value(0);
+    let d: Vec<_> = d.as_primitive::<Float64Type>().iter().collect();
+    assert_eq!(d, [Some(1.5), Some(2.0), None]);
+
+    let m = s.column_by_name("m").unwrap().as_map();
+    let keys: Vec<_> = m.keys().as_string::<i32>().iter().collect();
+    assert_eq!(keys, [Some("k"), Some("l")]);
+    let values: Vec<_> = m.values().as_primitive::<Int32Type>().iter().collect();
+    assert_eq!(values, [Some(1), None]);
+}
 
+#[tokio::test]
+async fn test_from_json_mismatched_types() {
+    let sql = r#"select get_field(s, 'a') a, get_field(s, 'b') b, get_field(s, 'c') c
+        from (select from_json('{"a": "x", "b": 300, "c": 1}', 'a INT, b TINYINT, c FLOAT') s)"#;
     let expected = [
-        "+------------------+--------------------------------------------------------+",
-        "| name             | json_get_str(test.json_data,Utf8(\"foo\")) = Utf8(\"abc\") |",
-        "+------------------+--------------------------------------------------------+",
-        "| object_foo       | true                                                   |",
-        "| object_foo_array |                                                        |",
-        "| object_foo_obj   |                                                        |",
-        "| object_foo_null  |                                                        |",
-        "| object_bar       |                                                        |",
-        "| list_foo         |                                                        |",
-        "| invalid_json     |                                                        |",
-        "+------------------+--------------------------------------------------------+",
+        "+---+---+-----+",
+        "| a | b | c   |",
+        "+---+---+-----+",
+        "|   |   | 1.0 |",
+        "+---+---+-----+",
     ];
+    let batches = run_query(sql).await.unwrap();
     assert_batches_eq!(expected, &batches);
 }
 
 #[tokio::test]
-async fn test_json_get_str_int() {
-    let sql = r#"select json_get_str('["a", "b", "c"]', 1)"#;
-    let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Utf8, "b".to_string()));
-
-    let sql = r#"select json_get_str('["a", "b", "c"]', 3)"#;
+async fn test_from_json_decimal_temporal_and_binary() {
+    let sql = r#"select get_field(s, 'd') d, get_field(s, 'day') day, get_field(s, 'ts') ts, get_field(s, 'b') b,
+        get_field(s, 'bad') bad
+        from (select from_json(
+            '{"d": 123.456, "day": "2024-02-29", "ts": "2024-02-29T12:30:00+01:00", "b": "00ff10", "bad": "0g"}',
+            'd DECIMAL(6, 2), day DATE, ts TIMESTAMP, b BINARY, bad BINARY'
+        ) s)"#;
+    let expected = [
+        "+--------+------------+----------------------+--------+-----+",
+        "| d      | day        | ts                   | b      | bad |",
+        "+--------+------------+----------------------+--------+-----+",
+        "| 123.46 | 2024-02-29 | 2024-02-29T11:30:00Z | 00ff10 |     |",
+        "+--------+------------+----------------------+--------+-----+",
+    ];
     let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Utf8, String::new()));
+    assert_batches_eq!(expected, &batches);
 }
 
 #[tokio::test]
-async fn test_json_get_str_path() {
-    let sql = r#"select json_get_str('{"a": {"aa": "x", "ab: "y"}, "b": []}', 'a', 'aa')"#;
-    let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Utf8, "x".to_string()));
+async fn test_from_json_invalid_schema() {
+    let err = run_query("select from_json(json_data, 'a INT, b TIME') from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: Invalid schema 'a INT, b TIME' at position 9: expected a type"
+    );
+
+    let err = run_query("select from_json(json_data, name) from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: The second argument to 'from_json' must be a literal schema string."
+    );
 }
 
 #[tokio::test]
@@ -300,13 +1025,13 @@ async fn test_json_get_str_null() {
 #[tokio::test]
 async fn test_json_get_no_path() {
     let batches = run_query(r#"select json_get('"foo"')::string"#).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Utf8, "foo".to_string()));
+    assert_eq!(display_val(batches).await, (DataType::Utf8View, "foo".to_string()));
 
     let batches = run_query(r"select json_get('123')::int").await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Int64, "123".to_string()));
+    assert_eq!(display_val(batches).await, (DataType::Int32, "123".to_string()));
 
     let batches = run_query(r"select json_get('true')::int").await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Int64, String::new()));
+    assert_eq!(display_val(batches).await, (DataType::Int32, String::new()));
 }
 
 #[tokio::test]
@@ -394,19 +1119,143 @@ async fn test_json_get_path() {
 async fn test_json_get_cast_int() {
     let sql = r#"select json_get('{"foo": 42}', 'foo')::int"#;
     let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Int64, "42".to_string()));
+    assert_eq!(display_val(batches).await, (DataType::Int32, "42".to_string()));
 
     // floats not allowed
     let sql = r#"select json_get('{"foo": 4.2}', 'foo')::int"#;
     let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Int64, String::new()));
+    assert_eq!(display_val(batches).await, (DataType::Int32, String::new()));
+}
+
+#[tokio::test]
+async fn test_json_get_cast_narrow_types() {
+    let json = r#"'{"small": 42, "big": 18446744073709551615, "neg": -1, "f": 1.5, "large": 3000000000, "mid": 300}'"#;
+    let cases = [
+        ("cast(json_get({json}, 'small') as smallint)", DataType::Int16, "42"),
+        ("cast(json_get({json}, 'small') as tinyint)", DataType::Int8, "42"),
+        (
+            "cast(json_get({json}, 'small') as int unsigned)",
+            DataType::UInt32,
+            "42",
+        ),
+        (
+            "cast(json_get({json}, 'big') as bigint unsigned)",
+            DataType::UInt64,
+            "18446744073709551615",
+        ),
+        ("cast(json_get({json}, 'neg') as bigint unsigned)", DataType::UInt64, ""),
+        ("cast(json_get({json}, 'f') as real)", DataType::Float32, "1.5"),
+        ("cast(json_get({json}, 'large') as int)", DataType::Int32, ""),
+        ("cast(json_get({json}, 'mid') as tinyint)", DataType::Int8, ""),
+        (
+            "cast(json_get({json}, 'large') as int unsigned)",
+            DataType::UInt32,
+            "3000000000",
+        ),
+        ("cast(json_get({json}, 'big') as int unsigned)", DataType::UInt32, ""),
+        ("cast(json_get({json}, 'mid') as tinyint unsigned)", DataType::UInt8, ""),
+    ];
+    for (expr, data_type, expected) in cases {
+        let sql = format!("select {}", expr.replace("{json}", json));
+        let batches = run_query(&sql).await.unwrap();
+        assert_eq!(display_val(batches).await, (data_type, expected.to_string()), "{expr}");
+    }
+
+    // a value out of range for the narrower type is null, rather than failing the whole query
+    let sql = r#"select json_get(j, 'a')::int v from (values ('{"a": 1}'), ('{"a": 3000000000}')) as t(j)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(["+---+", "| v |", "+---+", "| 1 |", "|   |", "+---+"], &batches);
+
+    let lines = logical_plan(r"explain select cast(json_data->'foo' as int unsigned) from test").await;
+    assert_eq!(
+        lines[0],
+        "Projection: TRY_CAST(json_get_uint(test.json_data, Utf8(\"foo\")) AS UInt32) AS json_data -> 'foo'"
+    );
 }
 
 #[tokio::test]
 async fn test_json_get_cast_int_path() {
     let sql = r#"select json_get('{"foo": [null, {"x": false, "bar": 73}}', 'foo', 1, 'bar')::int"#;
     let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Int64, "73".to_string()));
+    assert_eq!(display_val(batches).await, (DataType::Int32, "73".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_negative_numbers() {
+    let batches = run_query(r#"select json_get_int('{"foo": -42}', 'foo')"#)
+        .await
+        .unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "-42".to_string()));
+
+    let batches = run_query(r#"select json_get_float('{"foo": -1.5}', 'foo')"#)
+        .await
+        .unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Float64, "-1.5".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_big_int() {
+    let json = r#"'{"a": 18446744073709551616, "b": -9223372036854775809}'"#;
+
+    let batches = run_query(&format!("select json_get({json}, 'a')")).await.unwrap();
+    let (value_type, value_repr) = display_val(batches).await;
+    assert!(matches!(value_type, DataType::Union(_, _)));
+    assert_eq!(value_repr, "{bigint=18446744073709551616}");
+
+    let batches = run_query(&format!("select json_get_int({json}, 'b')")).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, String::new()));
+
+    let batches = run_query(&format!("select json_get_float({json}, 'a')")).await.unwrap();
+    let (value_type, value_repr) = display_val(batches).await;
+    assert_eq!(value_type, DataType::Float64);
+    assert_eq!(value_repr, "1.8446744073709552e19");
+
+    let batches = run_query(&format!("select json_as_text({json}, 'b')")).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Utf8, "-9223372036854775809".to_string())
+    );
+
+    let batches = run_query(&format!("select json_union_to_text(json_get({json}, 'b'))"))
+        .await
+        .unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Utf8View, "-9223372036854775809".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_big_int_column() {
+    let sql = r#"select json_get_str(json_get(doc, 'a')) v from
+        (values ('{"a": 1}'), ('{"a": 99999999999999999999}'), ('{"a": "x"}')) as t(doc)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(
+        ["+---+", "| v |", "+---+", "|   |", "|   |", "| x |", "+---+"],
+        &batches
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_uint() {
+    let json = r#"'{"max": 18446744073709551615, "small": 7, "neg": -1, "big": 18446744073709551616, "s": "12"}'"#;
+    let cases = [
+        ("max", "18446744073709551615"),
+        ("small", "7"),
+        ("neg", ""),
+        ("big", ""),
+        ("s", "12"),
+    ];
+    for (key, expected) in cases {
+        let batches = run_query(&format!("select json_get_uint({json}, '{key}')"))
+            .await
+            .unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::UInt64, expected.to_string()),
+            "{key}"
+        );
+    }
 }
 
 #[tokio::test]
@@ -435,6 +1284,39 @@ async fn test_json_get_int_lookup() {
     assert_batches_eq!(expected, &batches);
 }
 
+#[tokio::test]
+async fn test_json_get_narrow_int_lookup() {
+    let expected = [
+        "+---------+-----------+",
+        "| int_key | json_data |",
+        "+---------+-----------+",
+        "| 0       |  [42]     |",
+        "+---------+-----------+",
+    ];
+    for int_type in ["Int8", "UInt32", "Dictionary(Int8, Int16)", "Dictionary(Int32, UInt8)"] {
+        let sql = format!(
+            "select int_key, json_data from other where json_get_int(json_data, arrow_cast(int_key, '{int_type}')) is not null"
+        );
+        let batches = run_query(&sql).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    }
+
+    let batches = run_query("select json_get_int('[1, 2, 3]', arrow_cast(1, 'Int32'))")
+        .await
+        .unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "2".to_string()));
+
+    let batches = run_query("select json_get_int('[1, 2, 3]', arrow_cast(-1, 'Int16'))")
+        .await
+        .unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "3".to_string()));
+
+    let batches = run_query("select json_get_int('[1, 2, 3]', arrow_cast(7, 'UInt8'))")
+        .await
+        .unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, String::new()));
+}
+
 #[tokio::test]
 async fn test_json_get_float() {
     let batches = run_query("select json_get_float('[1.5]', 0)").await.unwrap();
@@ -449,6 +1331,10 @@ async fn test_json_get_float() {
 async fn test_json_get_cast_float() {
     let sql = r#"select json_get('{"foo": 4.2e2}', 'foo')::float"#;
     let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Float32, "420.0".to_string()));
+
+    let sql = r#"select json_get('{"foo": 4.2e2}', 'foo')::double"#;
+    let batches = run_query(sql).await.unwrap();
     assert_eq!(display_val(batches).await, (DataType::Float64, "420.0".to_string()));
 }
 
@@ -456,14 +1342,339 @@ async fn test_json_get_cast_float() {
 async fn test_json_get_cast_numeric() {
     let sql = r#"select json_get('{"foo": 4.2e2}', 'foo')::numeric"#;
     let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Float64, "420.0".to_string()));
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Decimal128(38, 10), "420.0000000000".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_cast_numeric_equals() {
+    let sql = r#"select json_get('{"foo": 420}', 'foo')::numeric = 420"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Boolean, "true".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_get_cast_decimal() {
+    let sql = r#"select json_get('{"price": 12345678901234567.89}', 'price')::decimal(20, 2)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Decimal128(20, 2), "12345678901234567.89".to_string())
+    );
+
+    let lines = logical_plan(r"explain select (json_data->'foo')::decimal(10, 2) from test").await;
+    assert_eq!(
+        lines[0],
+        "Projection: json_get_decimal(test.json_data, Utf8(\"foo\"), Int64(10), Int64(2)) AS json_data -> 'foo'"
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_decimal() {
+    let json = r#"'{"a": 1.25, "b": "-3.5", "c": 123456, "d": true, "e": 1e2}'"#;
+    let cases = [("a", "1.25"), ("b", "-3.50"), ("c", ""), ("d", ""), ("e", "100.00")];
+    for (key, expected) in cases {
+        let batches = run_query(&format!("select json_get_decimal({json}, '{key}', 5, 2)"))
+            .await
+            .unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Decimal128(5, 2), expected.to_string()),
+            "{key}"
+        );
+    }
+
+    let sql = "select json_get_decimal('[1, 123456789012345678901234567890123456789012.5]', 1, 50, 1)";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (
+            DataType::Decimal256(50, 1),
+            "123456789012345678901234567890123456789012.5".to_string()
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_decimal_rounding() {
+    let json = r#"'{"a": 1.999, "b": -0.019, "c": 1.994, "d": "-2.005", "e": 15e-3, "f": 999.995}'"#;
+    let cases = [
+        ("a", "2.00"),
+        ("b", "-0.02"),
+        ("c", "1.99"),
+        ("d", "-2.01"),
+        ("e", "0.02"),
+        ("f", ""),
+    ];
+    for (key, expected) in cases {
+        let batches = run_query(&format!("select json_get_decimal({json}, '{key}', 5, 2)"))
+            .await
+            .unwrap();
+        assert_eq!(display_val(batches).await.1, expected, "{key}");
+    }
+}
+
+#[tokio::test]
+async fn test_json_get_decimal_column() {
+    let sql = "select name, json_get_decimal(json_data, 'foo', 10, 3) v from test";
+    let expected = [
+        "+------------------+---+",
+        "| name             | v |",
+        "+------------------+---+",
+        "| object_foo       |   |",
+        "| object_foo_array |   |",
+        "| object_foo_obj   |   |",
+        "| object_foo_null  |   |",
+        "| object_bar       |   |",
+        "| list_foo         |   |",
+        "| invalid_json     |   |",
+        "+------------------+---+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+
+    let sql = r#"select json_get_decimal(doc, 'n', 6, 2) v
+        from (values ('{"n": 0.1}'), ('{"n": 99.99}'), ('{"n": 12345.6}')) as t(doc)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(
+        [
+            "+-------+",
+            "| v     |",
+            "+-------+",
+            "| 0.10  |",
+            "| 99.99 |",
+            "|       |",
+            "+-------+"
+        ],
+        &batches
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_decimal_invalid_precision() {
+    let err = run_query("select json_get_decimal('1', 0, 2)").await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: Invalid precision 0 and scale 2 for 'json_get_decimal', the precision must be \
+         between 1 and 76 and the scale can't be greater than the precision."
+    );
+
+    let err = run_query("select json_get_decimal(json_data, 'foo', 'x', 2) from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: The precision and scale of 'json_get_decimal' must be integer literals."
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_timestamp() {
+    let json = r#"'{"iso": "2024-01-02T03:04:05Z", "offset": "2024-01-02T04:04:05+01:00", "naive": "2024-01-02 03:04:05",
+        "secs": 1704164645, "millis": 1704164645000, "frac": 1704164645.5, "custom": "02/01/2024 03:04:05",
+        "bad": "nope", "obj": {}}'"#;
+    let cases = [
+        ("iso", "", "1704164645000000000"),
+        ("offset", "", "1704164645000000000"),
+        ("naive", "", "1704164645000000000"),
+        ("naive", "null, null, '+01:00'", "1704161045000000000"),
+        ("secs", "", "1704164645000000000"),
+        ("millis", "null, 'ms', null", "1704164645000000000"),
+        ("frac", "", "1704164645500000000"),
+        ("custom", "'%d/%m/%Y %H:%M:%S', null, null", "1704164645000000000"),
+        ("bad", "", ""),
+        ("obj", "", ""),
+    ];
+    for (key, options, expected) in cases {
+        let sql = if options.is_empty() {
+            format!("select arrow_cast(json_get_timestamp({json}, '{key}'), 'Int64')")
+        } else {
+            format!("select arrow_cast(json_get_timestamp_with({json}, {options}, '{key}'), 'Int64')")
+        };
+        let batches = run_query(&sql).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Int64, expected.to_string()),
+            "{key} {options}"
+        );
+    }
+
+    let batches = run_query(&format!("select json_get_timestamp({json}, 'iso')"))
+        .await
+        .unwrap();
+    assert_eq!(
+        display_val(batches).await.0,
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    );
+    let batches = run_query(&format!(
+        "select json_get_timestamp_with({json}, null, null, 'Europe/London', 'iso')"
+    ))
+    .await
+    .unwrap();
+    assert_eq!(
+        display_val(batches).await.0,
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("Europe/London".into()))
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_date() {
+    let json = r#"'{"date": "2024-01-02", "iso": "2024-01-02T23:04:05-05:00", "secs": 1704164645, "custom": "02/01/2024",
+        "bad": "2024-13-01"}'"#;
+    let cases = [
+        ("date", "", "2024-01-02"),
+        ("iso", "", "2024-01-02"),
+        ("secs", "", "2024-01-02"),
+        ("custom", "'%d/%m/%Y', null", "2024-01-02"),
+        ("bad", "", ""),
+    ];
+    for (key, options, expected) in cases {
+        let sql = if options.is_empty() {
+            format!("select json_get_date({json}, '{key}')")
+        } else {
+            format!("select json_get_date_with({json}, {options}, '{key}')")
+        };
+        let batches = run_query(&sql).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Date32, expected.to_string()),
+            "{key} {options}"
+        );
+    }
+
+    let sql = r#"select json_get_date(doc, 'd') v
+        from (values ('{"d": "2024-01-02"}'), ('{"d": 0}'), ('{"d": null}')) as t(doc)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(
+        [
+            "+------------+",
+            "| v          |",
+            "+------------+",
+            "| 2024-01-02 |",
+            "| 1970-01-01 |",
+            "|            |",
+            "+------------+",
+        ],
+        &batches
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_time() {
+    let json = r#"'{"time": "03:04:05", "secs": 11045, "millis": 11045500, "custom": "03.04.05", "late": 90000}'"#;
+    let cases = [
+        ("time", "", "03:04:05"),
+        ("secs", "", "03:04:05"),
+        ("millis", "null, 'ms'", "03:04:05.500"),
+        ("custom", "'%H.%M.%S', null", "03:04:05"),
+        ("late", "", ""),
+    ];
+    for (key, options, expected) in cases {
+        let sql = if options.is_empty() {
+            format!("select json_get_time({json}, '{key}')")
+        } else {
+            format!("select json_get_time_with({json}, {options}, '{key}')")
+        };
+        let batches = run_query(&sql).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Time64(TimeUnit::Nanosecond), expected.to_string()),
+            "{key} {options}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_json_get_temporal_invalid_options() {
+    let err = run_query("select json_get_timestamp_with(json_data, null, 'h', null, 'foo') from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: Unknown unit 'h' for 'json_get_timestamp_with', expected 's', 'ms', 'us' or 'ns'."
+    );
+
+    let err = run_query("select json_get_timestamp_with(json_data, null, null, 'Mars/Base', 'foo') from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: Unknown timezone 'Mars/Base' for 'json_get_timestamp_with'."
+    );
+
+    let err = run_query("select json_get_date_with(json_data, name, null, 'foo') from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: The 'format' option of 'json_get_date_with' must be a string literal or null."
+    );
+
+    let err = run_query("select json_get_time_with(json_data, null) from test")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error during planning: The 'json_get_time_with' function requires a JSON argument, the options (format, \
+         unit), then an optional path."
+    );
+}
+
+#[tokio::test]
+async fn test_json_get_temporal_option_like_keys() {
+    // keys which look like `name=value` options are still path keys
+    let sql = r#"select json_get_timestamp('{"tz=UTC": "2024-01-02T03:04:05Z"}', 'tz=UTC')::text"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Utf8View, "2024-01-02T03:04:05Z".to_string())
+    );
 }
 
 #[tokio::test]
-async fn test_json_get_cast_numeric_equals() {
-    let sql = r#"select json_get('{"foo": 420}', 'foo')::numeric = 420"#;
+async fn test_json_get_cast_temporal() {
+    let sql = r#"select json_get('{"ts": "2024-01-02T03:04:05Z"}', 'ts')::timestamp"#;
     let batches = run_query(sql).await.unwrap();
-    assert_eq!(display_val(batches).await, (DataType::Boolean, "true".to_string()));
+    assert_eq!(
+        display_val(batches).await,
+        (
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            "2024-01-02T03:04:05".to_string()
+        )
+    );
+
+    let sql = r#"select json_get('{"d": "2024-01-02"}', 'd')::date"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Date32, "2024-01-02".to_string()));
+
+    let lines = logical_plan(r"explain select (json_data->'foo')::date from test").await;
+    assert_eq!(
+        lines[0],
+        "Projection: json_get_date(test.json_data, Utf8(\"foo\")) AS json_data -> 'foo'"
+    );
+
+    // the session timezone is the timezone of `timestamptz`
+    let ctx = create_context().await.unwrap();
+    ctx.sql("set datafusion.execution.time_zone = '+01:00'").await.unwrap();
+    let sql = r#"select json_get(doc, 'ts')::timestamptz v from (values ('{"ts": "2024-01-02 03:04:05"}')) as t(doc)"#;
+    let df = ctx.sql(sql).await.unwrap();
+    let plan = df.clone().into_optimized_plan().unwrap().display_indent().to_string();
+    assert!(
+        plan.contains("json_get_timestamp_with(column1, Utf8(NULL), Utf8(NULL), Utf8(\"+01:00\"), Utf8(\"ts\"))"),
+        "{plan}"
+    );
+    assert_eq!(
+        display_val(df.collect().await.unwrap()).await,
+        (
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".into())),
+            "2024-01-02T03:04:05+01:00".to_string()
+        )
+    );
 }
 
 #[tokio::test]
@@ -933,6 +2144,27 @@ async fn test_json_get_nested_collapsed() {
     .await;
 }
 
+#[tokio::test]
+async fn test_json_get_nested_multiple_values_not_collapsed() {
+    // the inner call returns the first match of a wildcard or filter, or an array of a slice's elements, so the
+    // outer path can't be appended to its path
+    let cases = [
+        (r#"json_get_int(json_get('[{"b": 1}, {"a": 2}]', '$[*]'), 'a')"#, ""),
+        (
+            r"json_query(json_get('[[1, 2], [3, 4], [5]]', '$[0:2]'), '$[*]')",
+            "[{array=[1, 2]}, {array=[3, 4]}]",
+        ),
+        (
+            r#"json_get_str(json_get('[{"k": 1}, {"k": 2, "v": "x"}]', '$[?(@.k == 1)]'), 'v')"#,
+            "",
+        ),
+    ];
+    for (expr, expected) in cases {
+        let batches = run_query(&format!("select {expr}")).await.unwrap();
+        assert_eq!(display_val(batches).await.1, expected, "{expr}");
+    }
+}
+
 #[tokio::test]
 async fn test_json_get_cte() {
     // avoid auto-un-nesting with a CTE
@@ -1084,7 +2316,7 @@ async fn test_json_get_int_unnest() {
 async fn test_plan_json_get_int_unnest() {
     let sql = "explain select json_get(json_get(json_data, 'foo'), 0)::int v from test";
     let expected = [
-        "Projection: json_get_int(test.json_data, Utf8(\"foo\"), Int64(0)) AS v",
+        "Projection: TRY_CAST(json_get_int(test.json_data, Utf8(\"foo\"), Int64(0)) AS Int32) AS v",
         "  TableScan: test projection=[json_data]",
     ];
 
@@ -1095,11 +2327,36 @@ async fn test_plan_json_get_int_unnest() {
 #[tokio::test]
 async fn test_multiple_lookup_arrays() {
     let sql = "select json_get(json_data, str_key1, str_key2) v from more_nested";
-    let err = run_query(sql).await.unwrap_err();
-    assert_eq!(
-        err.to_string(),
-        "Execution error: More than 1 path element is not supported when querying JSON using an array."
-    );
+    let expected = [
+        "+-------------+",
+        "| v           |",
+        "+-------------+",
+        "| {array=[0]} |",
+        "| {null=}     |",
+        "| {null=}     |",
+        "+-------------+",
+    ];
+
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_mixed_scalar_and_array_lookups() {
+    let sql = "select json_get_int(json_data, str_key1, 'bar', int_key) v from more_nested";
+    #[rustfmt::skip]
+    let expected = [
+        "+---+",
+        "| v |",
+        "+---+",
+        "| 0 |",
+        "| 1 |",
+        "|   |",
+        "+---+",
+    ];
+
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(expected, &batches);
 }
 
 #[tokio::test]
@@ -1308,7 +2565,7 @@ async fn test_arrow_cast_int() {
     for_all_json_datatypes(async |dt| {
         let batches = run_query_datatype(sql, dt).await.unwrap();
         assert_batches_eq!(expected, &batches);
-        assert_eq!(display_val(batches).await, (DataType::Int64, "42".to_string()));
+        assert_eq!(display_val(batches).await, (DataType::Int32, "42".to_string()));
     })
     .await;
 }
@@ -1318,7 +2575,7 @@ async fn test_plan_arrow_cast_int() {
     let lines = logical_plan(r"explain select (json_data->'foo')::int from test").await;
 
     let expected = [
-        "Projection: json_get_int(test.json_data, Utf8(\"foo\")) AS json_data -> 'foo'",
+        "Projection: TRY_CAST(json_get_int(test.json_data, Utf8(\"foo\")) AS Int32) AS json_data -> 'foo'",
         "  TableScan: test projection=[json_data]",
     ];
 
@@ -1445,7 +2702,7 @@ async fn test_plan_arrow_double_nested_cast() {
     let lines = logical_plan(r"explain select (json_data->'foo'->0)::int from test").await;
 
     let expected = [
-        "Projection: json_get_int(test.json_data, Utf8(\"foo\"), Int64(0)) AS json_data -> 'foo' -> 0",
+        "Projection: TRY_CAST(json_get_int(test.json_data, Utf8(\"foo\"), Int64(0)) AS Int32) AS json_data -> 'foo' -> 0",
         "  TableScan: test projection=[json_data]",
     ];
 
@@ -1477,49 +2734,445 @@ async fn test_double_arrow_double_nested_cast() {
 }
 
 #[tokio::test]
-async fn test_plan_double_arrow_double_nested_cast() {
-    let lines = logical_plan(r"explain select (json_data->>'foo'->>0)::int from test").await;
+async fn test_plan_double_arrow_double_nested_cast() {
+    let lines = logical_plan(r"explain select (json_data->>'foo'->>0)::int from test").await;
+
+    // NB: json_as_text(..)::int is NOT the same as `json_get_int(..)`, hence the cast is not rewritten
+    let expected = [
+        "Projection: CAST(json_as_text(json_as_text(test.json_data, Utf8(\"foo\")), Int64(0)) AS Int32) AS json_data ->> 'foo' ->> 0",
+        "  TableScan: test projection=[json_data]",
+    ];
+
+    assert_eq!(lines, expected);
+}
+
+#[tokio::test]
+async fn test_arrow_nested_columns() {
+    let sql = "select json_data->str_key1->str_key2 v from more_nested";
+    let expected = [
+        "+-------------+",
+        "| v           |",
+        "+-------------+",
+        "| {array=[0]} |",
+        "| {null=}     |",
+        "| {null=}     |",
+        "+-------------+",
+    ];
+
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_arrow_nested_double_columns() {
+    let sql = "select json_data->str_key1->str_key2->int_key v from more_nested";
+    let expected = [
+        "+---------+",
+        "| v       |",
+        "+---------+",
+        "| {int=0} |",
+        "| {null=} |",
+        "| {null=} |",
+        "+---------+",
+    ];
+
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_lexical_precedence_correct() {
+    #[rustfmt::skip]
+    let expected = [
+        "+------+",
+        "| v    |",
+        "+------+",
+        "| true |",
+        "+------+",
+    ];
+    let sql = r#"select '{"a": "b"}'->>'a'='b' as v"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_question_mark_contains() {
+    let sql = "select name, json_data ? 'foo' from test";
+    let expected = [
+        "+------------------+-------------------+",
+        "| name             | json_data ? 'foo' |",
+        "+------------------+-------------------+",
+        "| object_foo       | true              |",
+        "| object_foo_array | true              |",
+        "| object_foo_obj   | true              |",
+        "| object_foo_null  | true              |",
+        "| object_bar       | false             |",
+        "| list_foo         | false             |",
+        "| invalid_json     | false             |",
+        "+------------------+-------------------+",
+    ];
+
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_contains_any_all() {
+    let json = r#"'{"a": 1, "b": {"c": 2, "d": 3}}'"#;
+    let cases = [
+        ("json_contains_any({json}, 'a', 'x')", "true"),
+        ("json_contains_any({json}, 'x', 'y')", "false"),
+        ("json_contains_all({json}, 'a', 'b')", "true"),
+        ("json_contains_all({json}, 'a', 'x')", "false"),
+        ("json_contains_all({json}, 'a', 'a')", "true"),
+        ("json_contains_any({json}, 'b', make_array('x', 'd'))", "true"),
+        ("json_contains_all({json}, 'b', make_array('c', 'd'))", "true"),
+        ("json_contains_all({json}, 'b', make_array('c', 'a'))", "false"),
+        ("json_contains_any({json}, 'a', make_array('c'))", "false"),
+    ];
+    for (expr, expected) in cases {
+        let sql = format!("select {}", expr.replace("{json}", json));
+        let batches = run_query(&sql).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Boolean, expected.to_string()),
+            "{expr}"
+        );
+    }
+
+    // keys from a column are checked for each row
+    let sql = r#"select json_contains_any(j, k) any_key, json_contains_all(j, 'b', make_array(k, 'c')) all_keys
+        from (values ('{"a": 1, "b": {"c": 2}}', 'a'), ('{"b": {"c": 2, "d": 3}}', 'd'), ('{"a": 1}', null)) as t(j, k)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(
+        [
+            "+---------+----------+",
+            "| any_key | all_keys |",
+            "+---------+----------+",
+            "| true    | false    |",
+            "| false   | true     |",
+            "| false   | false    |",
+            "+---------+----------+",
+        ],
+        &batches
+    );
+}
+
+#[tokio::test]
+async fn test_question_pipe_and() {
+    let sql =
+        "select name, json_data ?| '{foo,bar}' as any_key, json_data ?& array['foo', 'bar'] as all_keys from test";
+    let expected = [
+        "+------------------+---------+----------+",
+        "| name             | any_key | all_keys |",
+        "+------------------+---------+----------+",
+        "| object_foo       | true    | false    |",
+        "| object_foo_array | true    | false    |",
+        "| object_foo_obj   | true    | false    |",
+        "| object_foo_null  | true    | false    |",
+        "| object_bar       | true    | false    |",
+        "| list_foo         | false   | false    |",
+        "| invalid_json     | false   | false    |",
+        "+------------------+---------+----------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+
+    let cases = [
+        ("?& '{a,b}'", "true"),
+        ("?& '{}'", "true"),
+        ("?| '{}'", "false"),
+        ("?| 'b'", "true"),
+    ];
+    for (op, expected) in cases {
+        let batches = run_query(&format!(r#"select '{{"a": 1, "b": 2}}' {op}"#))
+            .await
+            .unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Boolean, expected.to_string()),
+            "{op}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_at_arrow_contains_value() {
+    let sql = r#"select name, json_data @> '{"foo": "abc"}' from test"#;
+    let expected = [
+        "+------------------+-------------------------------+",
+        "| name             | json_data @> '{\"foo\": \"abc\"}' |",
+        "+------------------+-------------------------------+",
+        "| object_foo       | true                          |",
+        "| object_foo_array | false                         |",
+        "| object_foo_obj   | false                         |",
+        "| object_foo_null  | false                         |",
+        "| object_bar       | false                         |",
+        "| list_foo         | false                         |",
+        "| invalid_json     |                               |",
+        "+------------------+-------------------------------+",
+    ];
+
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_contains_value() {
+    let cases = [
+        (
+            r#"{"status": "active", "tags": ["x", "y"], "n": 1}"#,
+            r#"{"status": "active", "tags": ["x"]}"#,
+            "true",
+        ),
+        (r#"{"a": {"b": 1, "c": 2}}"#, r#"{"a": {"b": 1.0}}"#, "true"),
+        (r#"{"a": [1, [2, 3]]}"#, r#"{"a": [[3]]}"#, "true"),
+        (r#"{"a": 1}"#, r#"{"a": 1, "b": 2}"#, "false"),
+        (r#"{"a": ["b"]}"#, r#"{"a": "b"}"#, "false"),
+        (r#"["a", "b"]"#, r#""a""#, "true"),
+        ("[1, 2]", "[[1]]", "false"),
+        ("[]", "[]", "true"),
+        ("null", "null", "true"),
+        (r#"{"a": 1, "a": 2}"#, r#"{"a": 2}"#, "true"),
+        (r#"{"a": 1, "a": 2}"#, r#"{"a": 1}"#, "false"),
+        (r#"{"a": 1}"#, "not json", ""),
+    ];
+    for (doc, pattern, expected) in cases {
+        let batches = run_query(&format!("select json_contains_value('{doc}', '{pattern}')"))
+            .await
+            .unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Boolean, expected.to_string()),
+            "{doc} @> {pattern}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_arrow_at_contained_in() {
+    let batches = run_query(r#"select '{"a": 1}' <@ '{"a": 1, "b": 2}'"#).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Boolean, "true".to_string()));
+
+    let sql = r#"select name from test where json_data <@ '{"foo": "abc", "bar": true}'"#;
+    let expected = [
+        "+------------+",
+        "| name       |",
+        "+------------+",
+        "| object_foo |",
+        "| object_bar |",
+        "+------------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_at_arrow_arrays_unchanged() {
+    let batches = run_query("select make_array(1, 2, 3) @> make_array(2)").await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Boolean, "true".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_path_exists() {
+    let doc = r#"{"items": [{"qty": 5}, {"qty": 12}]}"#;
+    let cases = [
+        ("$.items[*] ? (@.qty > 10)", "true"),
+        ("$.items[*] ? (@.qty > 20)", "false"),
+        ("$.items ? (@.qty > 10)", "true"),
+        ("lax $.items[last].qty", "true"),
+        (r#"$."items"[0]"#, "true"),
+        ("$.**.qty ? (@ == 12)", "true"),
+        ("$.missing", "false"),
+    ];
+    for (path, expected) in cases {
+        let batches = run_query(&format!("select json_path_exists('{doc}', '{path}')"))
+            .await
+            .unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Boolean, expected.to_string()),
+            "{path}"
+        );
+    }
+
+    for doc in ["null::text", "'not json'", r#"'{"a": 1'"#, r#"'{"a": 1} x'"#] {
+        let batches = run_query(&format!("select json_path_exists({doc}, '$.a')"))
+            .await
+            .unwrap();
+        assert_eq!(display_val(batches).await, (DataType::Boolean, String::new()), "{doc}");
+    }
+}
+
+#[tokio::test]
+async fn test_at_question_path_exists() {
+    let sql = r#"select name from test where json_data @? '$.foo ? (@ == "abc")'"#;
+    let expected = [
+        "+------------+",
+        "| name       |",
+        "+------------+",
+        "| object_foo |",
+        "+------------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+
+    let err = run_query("select '{}' @? 'strict $.a'").await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Invalid JSONPath 'strict $.a' at position 6: strict mode isn't supported"),
+        "unexpected error: {err}"
+    );
+
+    let err = run_query("select json_path_exists(json_data, name) from test")
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("The 'json_path_exists' function requires a JSON value and a literal JSONPath string."),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_json_path_match() {
+    let doc = r#"{"a": 1, "b": [1, 2, 3]}"#;
+    let cases = [
+        ("$.a == 1", "true"),
+        ("$.a <> 1", "false"),
+        ("$.b[*] > 2", "true"),
+        ("$.b[*] > 3", "false"),
+        ("$.a == 1 && !($.c == 1)", "true"),
+        ("$.b[*] ? (@ > 1) == 3", "true"),
+        ("$.c", "false"),
+    ];
+    for (predicate, expected) in cases {
+        let batches = run_query(&format!("select json_path_match('{doc}', '{predicate}')"))
+            .await
+            .unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Boolean, expected.to_string()),
+            "{predicate}"
+        );
+    }
+
+    let batches = run_query("select json_path_match('not json', '$.a == 1')")
+        .await
+        .unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Boolean, String::new()));
+}
+
+#[tokio::test]
+async fn test_at_at_path_match() {
+    let sql = r#"select name, json_data @@ '$.foo == "abc" || $.bar == true' as matched from test"#;
+    let expected = [
+        "+------------------+---------+",
+        "| name             | matched |",
+        "+------------------+---------+",
+        "| object_foo       | true    |",
+        "| object_foo_array | false   |",
+        "| object_foo_obj   | false   |",
+        "| object_foo_null  | false   |",
+        "| object_bar       | true    |",
+        "| list_foo         | false   |",
+        "| invalid_json     |         |",
+        "+------------------+---------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+
+    let err = run_query("select '{}' @@ '$.a ='").await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Invalid JSONPath '$.a =' at position 4: expected the end of the predicate"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_hash_arrow() {
+    let sql = "select name, json_data #> '{foo,0}' from test";
 
-    // NB: json_as_text(..)::int is NOT the same as `json_get_int(..)`, hence the cast is not rewritten
     let expected = [
-        "Projection: CAST(json_as_text(json_as_text(test.json_data, Utf8(\"foo\")), Int64(0)) AS Int32) AS json_data ->> 'foo' ->> 0",
-        "  TableScan: test projection=[json_data]",
+        "+------------------+------------------------+",
+        "| name             | json_data #> '{foo,0}' |",
+        "+------------------+------------------------+",
+        "| object_foo       | {null=}                |",
+        "| object_foo_array | {int=1}                |",
+        "| object_foo_obj   | {null=}                |",
+        "| object_foo_null  | {null=}                |",
+        "| object_bar       | {null=}                |",
+        "| list_foo         | {null=}                |",
+        "| invalid_json     | {null=}                |",
+        "+------------------+------------------------+",
     ];
 
-    assert_eq!(lines, expected);
-}
-
-#[tokio::test]
-async fn test_arrow_nested_columns() {
-    let sql = "select json_data->str_key1->str_key2 v from more_nested";
-    let expected = [
-        "+-------------+",
-        "| v           |",
-        "+-------------+",
-        "| {array=[0]} |",
-        "| {null=}     |",
-        "| {null=}     |",
-        "+-------------+",
+    let expected_dict = [
+        "+------------------+------------------------+",
+        "| name             | json_data #> '{foo,0}' |",
+        "+------------------+------------------------+",
+        "| object_foo       |                        |",
+        "| object_foo_array | {int=1}                |",
+        "| object_foo_obj   |                        |",
+        "| object_foo_null  |                        |",
+        "| object_bar       |                        |",
+        "| list_foo         |                        |",
+        "| invalid_json     |                        |",
+        "+------------------+------------------------+",
     ];
 
     for_all_json_datatypes(async |dt| {
         let batches = run_query_datatype(sql, dt).await.unwrap();
-        assert_batches_eq!(expected, &batches);
+        if matches!(dt, DataType::Dictionary(_, _)) {
+            assert_batches_eq!(expected_dict, &batches);
+        } else {
+            assert_batches_eq!(expected, &batches);
+        }
     })
     .await;
 }
 
 #[tokio::test]
-async fn test_arrow_nested_double_columns() {
-    let sql = "select json_data->str_key1->str_key2->int_key v from more_nested";
+async fn test_hash_long_arrow() {
+    let sql = "select name, json_data #>> '{foo}' from test";
+
     let expected = [
-        "+---------+",
-        "| v       |",
-        "+---------+",
-        "| {int=0} |",
-        "| {null=} |",
-        "| {null=} |",
-        "+---------+",
+        "+------------------+-----------------------+",
+        "| name             | json_data #>> '{foo}' |",
+        "+------------------+-----------------------+",
+        "| object_foo       | abc                   |",
+        "| object_foo_array | [1]                   |",
+        "| object_foo_obj   | {}                    |",
+        "| object_foo_null  |                       |",
+        "| object_bar       |                       |",
+        "| list_foo         |                       |",
+        "| invalid_json     |                       |",
+        "+------------------+-----------------------+",
     ];
 
     for_all_json_datatypes(async |dt| {
@@ -1530,44 +3183,25 @@ async fn test_arrow_nested_double_columns() {
 }
 
 #[tokio::test]
-async fn test_lexical_precedence_correct() {
+async fn test_hash_long_arrow_list_column() {
+    let sql = "select json_data #>> make_array(str_key) v from other";
+
     #[rustfmt::skip]
     let expected = [
-        "+------+",
-        "| v    |",
-        "+------+",
-        "| true |",
-        "+------+",
+        "+----+",
+        "| v  |",
+        "+----+",
+        "| 42 |",
+        "|    |",
+        "|    |",
+        "|    |",
+        "+----+",
     ];
-    let sql = r#"select '{"a": "b"}'->>'a'='b' as v"#;
+
     let batches = run_query(sql).await.unwrap();
     assert_batches_eq!(expected, &batches);
 }
 
-#[tokio::test]
-async fn test_question_mark_contains() {
-    let sql = "select name, json_data ? 'foo' from test";
-    let expected = [
-        "+------------------+-------------------+",
-        "| name             | json_data ? 'foo' |",
-        "+------------------+-------------------+",
-        "| object_foo       | true              |",
-        "| object_foo_array | true              |",
-        "| object_foo_obj   | true              |",
-        "| object_foo_null  | true              |",
-        "| object_bar       | false             |",
-        "| list_foo         | false             |",
-        "| invalid_json     | false             |",
-        "+------------------+-------------------+",
-    ];
-
-    for_all_json_datatypes(async |dt| {
-        let batches = run_query_datatype(sql, dt).await.unwrap();
-        assert_batches_eq!(expected, &batches);
-    })
-    .await;
-}
-
 #[tokio::test]
 async fn test_arrow_filter() {
     let sql = "select name from test where (json_data->>'foo') = 'abc'";
@@ -1883,6 +3517,24 @@ async fn test_dict_haystack_needle() {
     .await;
 }
 
+#[tokio::test]
+async fn test_dict_multiple_lookup_arrays() {
+    let sql = "select json_get(json_data, str_key1, str_key2) v from dicts";
+    let expected = [
+        "+-------------+",
+        "| v           |",
+        "+-------------+",
+        "| {array=[0]} |",
+        "|             |",
+        "|             |",
+        "|             |",
+        "+-------------+",
+    ];
+
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(expected, &batches);
+}
+
 #[tokio::test]
 async fn test_dict_length() {
     let sql = "select json_length(json_data) v from dicts";
@@ -2976,3 +4628,417 @@ async fn test_json_union_to_text_arms() {
     .await;
     assert_eq!(repr, "true");
 }
+
+// ============================================================================
+// json_build_object / json_build_array tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_json_build_object() {
+    let batches = run_query("select json_build_object('a', 1, 'b', 'x\"y', 'c', null, 'd', true, 'e', 1.5, 2, 'two')")
+        .await
+        .unwrap();
+    let metadata = batches[0].schema().field(0).metadata().clone();
+    assert_eq!(
+        metadata.get("ARROW:extension:name").map(String::as_str),
+        Some("arrow.json")
+    );
+    assert_eq!(
+        display_val(batches).await,
+        (
+            DataType::Utf8,
+            r#"{"a":1,"b":"x\"y","c":null,"d":true,"e":1.5,"2":"two"}"#.to_string()
+        )
+    );
+
+    let batches = run_query("select json_build_object()").await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "{}".to_string()));
+}
+
+#[tokio::test]
+async fn test_json_build_object_column() {
+    let sql = "select name, json_build_object('name', name, 'foo', json_get(json_data, 'foo')) as obj from test";
+    let expected = [
+        "+------------------+---------------------------------------+",
+        "| name             | obj                                   |",
+        "+------------------+---------------------------------------+",
+        "| object_foo       | {\"name\":\"object_foo\",\"foo\":\"abc\"}     |",
+        "| object_foo_array | {\"name\":\"object_foo_array\",\"foo\":[1]} |",
+        "| object_foo_obj   | {\"name\":\"object_foo_obj\",\"foo\":{}}    |",
+        "| object_foo_null  | {\"name\":\"object_foo_null\",\"foo\":null} |",
+        "| object_bar       | {\"name\":\"object_bar\",\"foo\":null}      |",
+        "| list_foo         | {\"name\":\"list_foo\",\"foo\":null}        |",
+        "| invalid_json     | {\"name\":\"invalid_json\",\"foo\":null}    |",
+        "+------------------+---------------------------------------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_json_build_object_invalid() {
+    let err = run_query("select json_build_object('a')").await.unwrap_err();
+    assert!(
+        err.to_string().contains(
+            "The 'json_build_object' function requires an even number of arguments, alternating keys and values."
+        ),
+        "unexpected error: {err}"
+    );
+
+    let err = run_query("select json_build_object(null::text, 1)").await.unwrap_err();
+    assert!(
+        err.to_string().contains("Object keys can't be null"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_json_build_array() {
+    let sql = r#"select json_build_array(1, 'a', null, 2.5, false, json_get('{"b": [1, 2]}', 'b'))"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Utf8, r#"[1,"a",null,2.5,false,[1, 2]]"#.to_string())
+    );
+
+    let batches = run_query("select json_build_array()").await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "[]".to_string()));
+
+    // strings marked as JSON are embedded as raw JSON
+    let sql = "select json_build_array(name, json_get_json(json_data, 'foo')) from test where name = 'object_foo_obj'";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Utf8, r#"["object_foo_obj",{}]"#.to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_to_json() {
+    let cases = [
+        (
+            "named_struct('a', 1, 'b', 'x', 'c', make_array(1, 2))",
+            r#"{"a":1,"b":"x","c":[1,2]}"#,
+        ),
+        ("make_array(1.5, null)", "[1.5,null]"),
+        ("map(['a', 'b'], [1, 2])", r#"{"a":1,"b":2}"#),
+        ("arrow_cast(12.30, 'Decimal128(5, 2)')", "12.30"),
+        ("'2024-01-02T03:04:05'::timestamp", r#""2024-01-02T03:04:05""#),
+        ("'2024-01-02'::date", r#""2024-01-02""#),
+        ("arrow_cast('ab', 'Binary')", r#""6162""#),
+        ("'a\"b'", r#""a\"b""#),
+        (r#"json_get('{"a": [1, 2]}', 'a')"#, "[1, 2]"),
+        ("null::int", ""),
+    ];
+    for (value, expected) in cases {
+        let batches = run_query(&format!("select to_json({value})")).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Utf8, expected.to_string()),
+            "{value}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_to_json_column() {
+    let sql = "select name, to_json(named_struct('name', name, 'foo', json_get(json_data, 'foo'))) as json from test";
+    let batches = run_query(sql).await.unwrap();
+    let schema = batches[0].schema();
+    assert_eq!(
+        schema
+            .field(1)
+            .metadata()
+            .get("ARROW:extension:name")
+            .map(String::as_str),
+        Some("arrow.json")
+    );
+    let expected = [
+        "+------------------+---------------------------------------+",
+        "| name             | json                                  |",
+        "+------------------+---------------------------------------+",
+        "| object_foo       | {\"name\":\"object_foo\",\"foo\":\"abc\"}     |",
+        "| object_foo_array | {\"name\":\"object_foo_array\",\"foo\":[1]} |",
+        "| object_foo_obj   | {\"name\":\"object_foo_obj\",\"foo\":{}}    |",
+        "| object_foo_null  | {\"name\":\"object_foo_null\",\"foo\":null} |",
+        "| object_bar       | {\"name\":\"object_bar\",\"foo\":null}      |",
+        "| list_foo         | {\"name\":\"list_foo\",\"foo\":null}        |",
+        "| invalid_json     | {\"name\":\"invalid_json\",\"foo\":null}    |",
+        "+------------------+---------------------------------------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_json_agg() {
+    let batches = run_query("select json_agg(name order by name desc) from test")
+        .await
+        .unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (
+            DataType::Utf8,
+            r#"["object_foo_obj","object_foo_null","object_foo_array","object_foo","object_bar","list_foo","invalid_json"]"#
+                .to_string()
+        )
+    );
+
+    let sql = "select json_agg(json_get(json_data, 'foo') order by name) from test where name like 'object_foo%'";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Utf8, r#"["abc",[1],null,{}]"#.to_string())
+    );
+
+    let sql = "select json_agg(v order by k, v desc) from (values ('b', 1), ('a', 2), ('b', 3), ('a', 4)) t(k, v)";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "[4,2,3,1]".to_string()));
+
+    let batches = run_query("select json_agg(v) from (values (1)) t(v) where v > 1")
+        .await
+        .unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, String::new()));
+}
+
+#[tokio::test]
+async fn test_json_agg_group_by() {
+    let sql = "select k, json_agg(v order by v desc) as vs from (values ('a', 1), ('a', 2), ('b', 3)) t(k, v) \
+               group by k order by k";
+    let batches = run_query(sql).await.unwrap();
+    let expected = [
+        "+---+-------+",
+        "| k | vs    |",
+        "+---+-------+",
+        "| a | [2,1] |",
+        "| b | [3]   |",
+        "+---+-------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_json_object_agg() {
+    let sql = "select json_object_agg(k, v order by k) from (values ('b', 1), ('a', 2), ('c', null)) t(k, v)";
+    let batches = run_query(sql).await.unwrap();
+    assert_eq!(
+        display_val(batches).await,
+        (DataType::Utf8, r#"{"a":2,"b":1,"c":null}"#.to_string())
+    );
+
+    let err = run_query("select json_object_agg(k, v) from (values (null::text, 1)) t(k, v)")
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("Object keys can't be null"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_json_set() {
+    let cases = [
+        (
+            r#"json_set('{"a": 1, "b": [1, 2]}', 'a', 2)"#,
+            r#"{"a": 2, "b": [1, 2]}"#,
+        ),
+        (r#"json_set('{"a": 1}', 'c', 'x')"#, r#"{"a": 1,"c":"x"}"#),
+        ("json_set('{}', 'a', true)", r#"{"a":true}"#),
+        (
+            r#"json_set('{"user": {"email": "a@b.c", "id": 1}}', '$.user.email', 'redacted')"#,
+            r#"{"user": {"email": "redacted", "id": 1}}"#,
+        ),
+        ("json_set('[1, 2, 3]', 1, null)", "[1, null, 3]"),
+        ("json_set('[1, 2, 3]', -1, 4)", "[1, 2, 4]"),
+        ("json_set('[1, 2]', 5, 3)", "[1, 2,3]"),
+        ("json_set('[]', 0, 1)", "[1]"),
+        (r#"json_set('{"a": 1}', 'b', 'c', 2)"#, r#"{"a": 1}"#),
+        (
+            r#"json_set('{"a": 1}', 'a', json_get('{"x": [1]}', 'x'))"#,
+            r#"{"a": [1]}"#,
+        ),
+        (r#"json_set('{"a": 1', 'a', 2)"#, ""),
+        (r#"json_set('{"a": 1}', null::text, 2)"#, ""),
+    ];
+    for (expr, expected) in cases {
+        let batches = run_query(&format!("select {expr}")).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Utf8, expected.to_string()),
+            "{expr}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_json_set_column() {
+    let batches = run_query("select name, json_set(json_data, 'foo', 1) as json from test")
+        .await
+        .unwrap();
+    let expected = [
+        "+------------------+-------------------------+",
+        "| name             | json                    |",
+        "+------------------+-------------------------+",
+        "| object_foo       |  {\"foo\": 1}             |",
+        "| object_foo_array |  {\"foo\": 1}             |",
+        "| object_foo_obj   |  {\"foo\": 1}             |",
+        "| object_foo_null  |  {\"foo\": 1}             |",
+        "| object_bar       |  {\"bar\": true,\"foo\":1}  |",
+        "| list_foo         |  [\"foo\"]                |",
+        "| invalid_json     |                         |",
+        "+------------------+-------------------------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+}
+
+#[tokio::test]
+async fn test_json_set_unsupported_path() {
+    let err = run_query(r#"select json_set('{"a": [1]}', '$.a[*]', 2)"#)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("The 'json_set' function only supports paths of object keys and array indices."),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_json_insert_replace() {
+    let cases = [
+        (r#"json_insert('{"a": 1}', 'a', 2)"#, r#"{"a": 1}"#),
+        (r#"json_insert('{"a": 1}', 'b', 2)"#, r#"{"a": 1,"b":2}"#),
+        (
+            r#"json_insert('{"a": [1]}', json_pointer('/a/-'), 2)"#,
+            r#"{"a": [1,2]}"#,
+        ),
+        (r#"json_replace('{"a": 1}', 'a', 2)"#, r#"{"a": 2}"#),
+        (r#"json_replace('{"a": 1}', 'b', 2)"#, r#"{"a": 1}"#),
+        ("json_replace('[1, 2]', 2, 3)", "[1, 2]"),
+    ];
+    for (expr, expected) in cases {
+        let batches = run_query(&format!("select {expr}")).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Utf8, expected.to_string()),
+            "{expr}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_json_remove() {
+    let cases = [
+        (r#"json_remove('{"a": 1, "b": 2}', 'a')"#, r#"{"b": 2}"#),
+        (r#"json_remove('{"a": 1, "b": 2}', 'b')"#, r#"{"a": 1}"#),
+        (
+            r#"json_remove('{"a": {"b": 1, "c": 2}}', '$.a.c')"#,
+            r#"{"a": {"b": 1}}"#,
+        ),
+        ("json_remove('[1, 2, 3]', 0)", "[2, 3]"),
+        ("json_remove('[1, 2, 3]', 1)", "[1, 3]"),
+        ("json_remove('[1, 2, 3]', -1)", "[1, 2]"),
+        ("json_remove('[1]', 0)", "[]"),
+        (r#"json_remove('{"a": 1}', 'x')"#, r#"{"a": 1}"#),
+        (r#"json_remove('{"a": 1', 'a')"#, ""),
+        // each argument is a separate path, removed in turn
+        (r#"json_remove('{"a": 1, "b": 2}', 'a', 'b')"#, "{}"),
+        (
+            r#"json_remove('{"a": 1, "b": 2, "c": 3}', '$.a', '$.c')"#,
+            r#"{"b": 2}"#,
+        ),
+        (
+            r#"json_remove('{"a": {"b": 1, "c": 2}, "d": 3}', make_array('a', 'b'), 'd')"#,
+            r#"{"a": {"c": 2}}"#,
+        ),
+        ("json_remove('[1, 2, 3]', 0, 0)", "[3]"),
+        (r#"json_remove('{"a": 1, "b": 2}', 'a', null::text)"#, ""),
+    ];
+    for (expr, expected) in cases {
+        let batches = run_query(&format!("select {expr}")).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Utf8, expected.to_string()),
+            "{expr}"
+        );
+    }
+
+    let sql =
+        r#"select json_remove(j, k, 'c') v from (values ('{"a": 1, "b": 2, "c": 3}', 'a'), ('[1]', 'x')) as t(j, k)"#;
+    let batches = run_query(sql).await.unwrap();
+    assert_batches_eq!(
+        [
+            "+----------+",
+            "| v        |",
+            "+----------+",
+            "| {\"b\": 2} |",
+            "| [1]      |",
+            "+----------+",
+        ],
+        &batches
+    );
+}
+
+#[tokio::test]
+async fn test_minus_remove() {
+    let sql = "select name, json_get_json(json_data) - 'foo' as json from test";
+    let expected = [
+        "+------------------+---------------+",
+        "| name             | json          |",
+        "+------------------+---------------+",
+        "| object_foo       | {}            |",
+        "| object_foo_array | {}            |",
+        "| object_foo_obj   | {}            |",
+        "| object_foo_null  | {}            |",
+        "| object_bar       | {\"bar\": true} |",
+        "| list_foo         | [\"foo\"]       |",
+        "| invalid_json     |               |",
+        "+------------------+---------------+",
+    ];
+    for_all_json_datatypes(async |dt| {
+        let batches = run_query_datatype(sql, dt).await.unwrap();
+        assert_batches_eq!(expected, &batches);
+    })
+    .await;
+
+    let batches = run_query("select json_get_json('[1, 2, 3]') - 1").await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "[1, 3]".to_string()));
+
+    let batches = run_query(r#"select ('{"a": [1, 2, 3]}' -> 'a') - 0"#).await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Utf8, "[2, 3]".to_string()));
+
+    // plain strings aren't assumed to be JSON, so `-` is arithmetic
+    let err = run_query("select json_data - 'foo' from test").await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Cannot coerce arithmetic expression Utf8View - Utf8 to valid types"),
+        "{err}"
+    );
+
+    // arithmetic isn't planned as `json_remove`
+    let batches = run_query("select 5 - 2").await.unwrap();
+    assert_eq!(display_val(batches).await, (DataType::Int64, "3".to_string()));
+}
+
+#[tokio::test]
+async fn test_hash_minus_remove() {
+    let cases = [
+        (
+            r#"json_get_json('{"a": {"b": 1, "c": 2}}') #- '{a,b}'"#,
+            r#"{"a": {"c": 2}}"#,
+        ),
+        (r#"json_get_json('[{"a": 1}, 2]') #- '{0,a}'"#, r"[{}, 2]"),
+        (r#"json_get_json('{"a": 1}') #- '{}'"#, r#"{"a": 1}"#),
+        (r#"('{"x": {"a": 1, "b": 2}}' -> 'x') #- '{a}'"#, r#"{"b": 2}"#),
+    ];
+    for (expr, expected) in cases {
+        let batches = run_query(&format!("select {expr}")).await.unwrap();
+        assert_eq!(
+            display_val(batches).await,
+            (DataType::Utf8, expected.to_string()),
+            "{expr}"
+        );
+    }
+}
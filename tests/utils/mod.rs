@@ -11,7 +11,7 @@ use datafusion::common::ParamValues;
 use datafusion::error::Result;
 use datafusion::execution::context::SessionContext;
 use datafusion::prelude::SessionConfig;
-use datafusion_functions_json::register_all;
+use datafusion_functions_json::{register_all, JsonGetMergeRule};
 
 pub async fn create_context() -> Result<SessionContext> {
     let config = SessionConfig::new().set_str("datafusion.sql_parser.dialect", "postgres");
@@ -246,6 +246,13 @@ pub async fn run_query_datatype(sql: &str, json_data_type: &DataType) -> Result<
     ctx.sql(sql).await?.collect().await
 }
 
+/// Run a query with [`JsonGetMergeRule`] added to the optimizer.
+pub async fn run_query_merged(sql: &str, json_data_type: &DataType) -> Result<Vec<RecordBatch>> {
+    let ctx = create_test_table(json_data_type).await?;
+    ctx.add_optimizer_rule(Arc::new(JsonGetMergeRule));
+    ctx.sql(sql).await?.collect().await
+}
+
 pub async fn run_query_params(
     sql: &str,
     json_data_type: &DataType,